@@ -1,8 +1,11 @@
-use criterion::{Criterion, criterion_group, criterion_main};
+// The crossover group needs the quadratic reference conversion, so it only
+// runs with `cargo bench --features bench`
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use std::hint::black_box;
 
 use anybase::convert_base;
-use anybase::Converter;
+use anybase::{BigInt, Converter};
 fn bench_convert_base(c: &mut Criterion) {
     let src_table = "0123456789abcdefghijklmnopqrstuvwxyz";
     let dst_table = "0123456789ABCDEF";
@@ -33,5 +36,69 @@ fn bench_convert_base(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_convert_base);
-criterion_main!(benches);
\ No newline at end of file
+// Quadratic digit-by-digit conversion vs divide-and-conquer, decimal to hex.
+// The two lines cross where the divide-and-conquer threshold should sit.
+#[cfg(feature = "bench")]
+fn bench_crossover(c: &mut Criterion) {
+    let converter = Converter::new(anybase::base::DEC, anybase::base::HEX);
+    let mut group = c.benchmark_group("crossover");
+    group.sample_size(10);
+    for digits in [300, 1_000, 3_000, 10_000, 30_000] {
+        // Pseudo-random digits so that no block is trivially zero
+        let input: String = (0..digits)
+            .map(|i: u64| char::from(b'0' + ((i * 7919 + 13) % 10) as u8))
+            .collect();
+        group.bench_with_input(BenchmarkId::new("quadratic", digits), &input, |b, input| {
+            b.iter(|| {
+                anybase::bench::convert_quadratic(
+                    black_box(input),
+                    converter.src_alphabet(),
+                    converter.dst_alphabet(),
+                )
+                .unwrap()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("divide_and_conquer", digits), &input, |b, input| {
+            b.iter(|| converter.convert(black_box(input)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+// 64-bit IDs, which take the native fast path
+fn bench_small(c: &mut Criterion) {
+    let base62 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let converter = Converter::new(anybase::base::DEC, base62);
+    let input = u64::MAX.to_string();
+    c.bench_function("small_id", |b| {
+        b.iter(|| converter.convert(black_box(&input)).unwrap())
+    });
+    c.bench_function("small_id_convert_base", |b| {
+        b.iter(|| convert_base(black_box(&input), anybase::base::DEC, base62).unwrap())
+    });
+}
+
+// BigInt multiplication and squaring across the algorithm thresholds
+fn bench_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul");
+    for limbs in [16, 64, 256, 1024, 4096] {
+        let bytes: Vec<u8> = (0..limbs * 4).map(|i| (i * 7 + 13) as u8).collect();
+        let a = BigInt::from_bytes_be(&bytes);
+        let b = BigInt::from_bytes_be(&bytes.iter().rev().copied().collect::<Vec<_>>());
+        group.bench_with_input(BenchmarkId::new("product", limbs), &limbs, |bench, _| {
+            bench.iter(|| black_box(&a) * black_box(&b))
+        });
+        group.bench_with_input(BenchmarkId::new("square", limbs), &limbs, |bench, _| {
+            bench.iter(|| black_box(&a) * black_box(&a))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_convert_base, bench_small, bench_mul);
+#[cfg(feature = "bench")]
+criterion_group!(crossover, bench_crossover);
+#[cfg(feature = "bench")]
+criterion_main!(benches, crossover);
+#[cfg(not(feature = "bench"))]
+criterion_main!(benches);
@@ -0,0 +1,847 @@
+use crate::big_int::BigInt;
+use crate::error::{Error, Result, Table};
+use crate::native::NativeInt;
+use crate::radix;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// A validated character table describing one base.
+///
+/// An `Alphabet` is validated once on construction and owns both lookup
+/// directions: char → digit for decoding and digit → char for encoding.
+/// The base of the alphabet is the number of characters in its table.
+///
+/// # Examples
+///
+/// ```
+/// use anybase::Alphabet;
+///
+/// let hex = Alphabet::new("0123456789abcdef").unwrap();
+/// assert_eq!(hex.base(), 16);
+/// assert_eq!(hex.digit('f'), Some(15));
+/// assert_eq!(hex.char(10), Some('a'));
+///
+/// let digits = hex.decode_digits("ff").unwrap();
+/// assert_eq!(digits, vec![15, 15]);
+/// assert_eq!(hex.encode_digits(&digits).unwrap(), "ff");
+/// ```
+///
+/// Alphabets are unsigned unless sign characters are configured with
+/// [`Alphabet::with_signs`], and hold integers only unless a radix point is
+/// configured with [`Alphabet::with_radix_point`]. Repeating fractions also
+/// need the marks set with [`Alphabet::with_repetend_marks`], and scientific
+/// notation the marker set with [`Alphabet::with_exponent_marker`].
+#[derive(Clone)]
+pub struct Alphabet {
+    table: String,
+
+    lookup: DigitLookup,
+    chars: Vec<char>,
+    /// Longest UTF-8 encoding of a character in the table, in bytes
+    max_char_len: usize,
+    /// Prefix marking negative values, if negative values are supported
+    negative_sign: Option<char>,
+    /// Optional prefix accepted on non-negative values when parsing
+    positive_sign: Option<char>,
+    /// Separator between the integer and fraction digits, if fractions are supported
+    radix_point: Option<char>,
+    /// Characters enclosing the repeating digits of a fraction, if supported
+    repetend_marks: Option<(char, char)>,
+    /// Separator between the significand and the exponent, if scientific
+    /// notation is supported
+    exponent_marker: Option<char>,
+}
+
+/// Marks bytes that are not in an ASCII table
+const NO_DIGIT: u8 = u8::MAX;
+
+/// char → digit lookup, specialized for the common all-ASCII tables
+#[derive(Clone)]
+enum DigitLookup {
+    /// Every character is ASCII: digits indexed by byte, [`NO_DIGIT`] for
+    /// bytes not in the table. Inputs are decoded byte by byte.
+    Ascii(Box<[u8; 256]>),
+    /// Any other table: `(char, digit)` pairs sorted by char for binary search
+    Sorted(Box<[(char, u32)]>),
+}
+
+impl DigitLookup {
+    fn new(chars: &[char]) -> Self {
+        if chars.iter().all(char::is_ascii) {
+            let mut bytes = Box::new([NO_DIGIT; 256]);
+            for (i, &ch) in chars.iter().enumerate() {
+                bytes[ch as usize] = i as u8;
+            }
+            return DigitLookup::Ascii(bytes);
+        }
+        let mut pairs: Vec<(char, u32)> = chars
+            .iter()
+            .enumerate()
+            .map(|(i, &ch)| (ch, i as u32))
+            .collect();
+        pairs.sort_unstable();
+        DigitLookup::Sorted(pairs.into_boxed_slice())
+    }
+
+    #[inline]
+    fn get(&self, ch: char) -> Option<u32> {
+        match self {
+            DigitLookup::Ascii(bytes) => {
+                let d = *bytes.get(ch as usize)?;
+                (d != NO_DIGIT).then_some(u32::from(d))
+            }
+            DigitLookup::Sorted(pairs) => pairs
+                .binary_search_by_key(&ch, |&(c, _)| c)
+                .ok()
+                .map(|i| pairs[i].1),
+        }
+    }
+}
+
+impl Alphabet {
+    /// Creates a new `Alphabet` from a character table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The characters of the alphabet, in digit order. Owned
+    ///   `String`s are taken over without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table:
+    /// - is empty ([`Error::EmptyTable`])
+    /// - contains a single character ([`Error::SingleCharacterTable`])
+    /// - contains duplicate characters ([`Error::DuplicateCharacter`])
+    /// - is too large to index with a `u32` digit ([`Error::SizeLimitExceeded`])
+    pub fn new(table: impl Into<String>) -> Result<Self> {
+        Alphabet::with_kind(table.into(), Table::Alphabet)
+    }
+
+    /// Creates a new `Alphabet`, tagging any error with the given table kind.
+    pub(crate) fn with_kind(table: String, kind: Table) -> Result<Self> {
+        if table.is_empty() {
+            return Err(Error::EmptyTable { table: kind });
+        }
+        let chars: Vec<char> = table.chars().collect();
+        // A single digit cannot represent any non-zero value
+        if chars.len() == 1 {
+            return Err(Error::SingleCharacterTable { table: kind });
+        }
+        let limit = u32::MAX as usize;
+        if chars.len() > limit {
+            return Err(Error::SizeLimitExceeded {
+                table: kind,
+                limit,
+                actual: chars.len(),
+            });
+        }
+        let mut seen: HashMap<char, usize> = HashMap::with_capacity(chars.len());
+        for (i, &ch) in chars.iter().enumerate() {
+            if let Some(first) = seen.insert(ch, i) {
+                return Err(Error::DuplicateCharacter {
+                    table: kind,
+                    ch,
+                    first,
+                    second: i,
+                });
+            }
+        }
+        Ok(Alphabet {
+            table,
+            lookup: DigitLookup::new(&chars),
+            max_char_len: chars.iter().map(|ch| ch.len_utf8()).max().unwrap_or(1),
+            chars,
+            negative_sign: None,
+            positive_sign: None,
+            radix_point: None,
+            repetend_marks: None,
+            exponent_marker: None,
+        })
+    }
+
+    /// Enables signed values, marked by a leading sign character.
+    ///
+    /// Negative values are prefixed with `negative`. When `positive` is
+    /// given, it is accepted as an optional prefix of non-negative values
+    /// when parsing, but never emitted. Zero is always formatted without a
+    /// sign, and `-0` parses as zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `negative` - The character marking negative values, e.g. `'-'`
+    /// * `positive` - The character optionally marking non-negative values, e.g. `Some('+')`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SignConflict`] if a sign character is in the table
+    /// or is another configured symbol, or if both signs are the same
+    /// character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt, Error, Table};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap().with_signs('-', Some('+')).unwrap();
+    /// assert_eq!(hex.parse("-ff").unwrap(), BigInt::from(-255i32));
+    /// assert_eq!(hex.parse("+ff").unwrap(), BigInt::from(255i32));
+    /// assert_eq!(hex.format(&BigInt::from(-255i32)).unwrap(), "-ff");
+    /// assert_eq!(hex.parse("-0").unwrap(), BigInt::zero());
+    ///
+    /// let base64url = Alphabet::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_").unwrap();
+    /// assert_eq!(
+    ///     base64url.with_signs('-', None),
+    ///     Err(Error::SignConflict { table: Table::Alphabet, ch: '-' })
+    /// );
+    /// ```
+    pub fn with_signs(self, negative: char, positive: Option<char>) -> Result<Self> {
+        self.with_signs_for(negative, positive, Table::Alphabet)
+    }
+
+    /// Like [`Alphabet::with_signs`], tagging any error with the given table kind.
+    pub(crate) fn with_signs_for(
+        mut self,
+        negative: char,
+        positive: Option<char>,
+        kind: Table,
+    ) -> Result<Self> {
+        self.negative_sign = None;
+        self.positive_sign = None;
+        for ch in std::iter::once(negative).chain(positive) {
+            if self.is_in_use(ch) || positive == Some(negative) {
+                return Err(Error::SignConflict { table: kind, ch });
+            }
+        }
+        self.negative_sign = Some(negative);
+        self.positive_sign = positive;
+        Ok(self)
+    }
+
+    /// Enables fractional values, with `point` separating the integer and
+    /// fraction digits.
+    ///
+    /// See [`Converter::convert_fraction`](crate::Converter::convert_fraction).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RadixPointConflict`] if `point` is in the table or is
+    /// another configured symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, Error, Table};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap().with_radix_point('.').unwrap();
+    /// assert_eq!(hex.radix_point(), Some('.'));
+    /// assert_eq!(
+    ///     Alphabet::new("0123456789abcdef").unwrap().with_radix_point('a'),
+    ///     Err(Error::RadixPointConflict { table: Table::Alphabet, ch: 'a' })
+    /// );
+    /// ```
+    pub fn with_radix_point(self, point: char) -> Result<Self> {
+        self.with_radix_point_for(point, Table::Alphabet)
+    }
+
+    /// Like [`Alphabet::with_radix_point`], tagging any error with the given table kind.
+    pub(crate) fn with_radix_point_for(mut self, point: char, kind: Table) -> Result<Self> {
+        self.radix_point = None;
+        if self.is_in_use(point) {
+            return Err(Error::RadixPointConflict {
+                table: kind,
+                ch: point,
+            });
+        }
+        self.radix_point = Some(point);
+        Ok(self)
+    }
+
+    /// Returns the character separating integer and fraction digits, if
+    /// fractions are enabled.
+    pub fn radix_point(&self) -> Option<char> {
+        self.radix_point
+    }
+
+    /// Enables repeating fractions, with the repeating digits enclosed in
+    /// `open` and `close`, as in `0.0(0011)`.
+    ///
+    /// See [`Converter::convert_rational`](crate::Converter::convert_rational).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RepetendConflict`] if a mark is in the table or is
+    /// another configured symbol, or if both marks are the same character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt};
+    ///
+    /// let dec = Alphabet::new("0123456789").unwrap()
+    ///     .with_radix_point('.').unwrap()
+    ///     .with_repetend_marks('(', ')').unwrap();
+    /// assert_eq!(dec.repetend_marks(), Some(('(', ')')));
+    /// assert_eq!(dec.format_rational(&BigInt::from(1u8), &BigInt::from(7u8), 64).unwrap(), "0.(142857)");
+    /// ```
+    pub fn with_repetend_marks(self, open: char, close: char) -> Result<Self> {
+        self.with_repetend_marks_for(open, close, Table::Alphabet)
+    }
+
+    /// Like [`Alphabet::with_repetend_marks`], tagging any error with the given table kind.
+    pub(crate) fn with_repetend_marks_for(
+        mut self,
+        open: char,
+        close: char,
+        kind: Table,
+    ) -> Result<Self> {
+        self.repetend_marks = None;
+        for ch in [open, close] {
+            if self.is_in_use(ch) || open == close {
+                return Err(Error::RepetendConflict { table: kind, ch });
+            }
+        }
+        self.repetend_marks = Some((open, close));
+        Ok(self)
+    }
+
+    /// Returns the characters enclosing repeating digits, if repeating
+    /// fractions are enabled.
+    pub fn repetend_marks(&self) -> Option<(char, char)> {
+        self.repetend_marks
+    }
+
+    /// Enables scientific notation, with `marker` separating the significand
+    /// from the exponent, as in `1.1p-1010`.
+    ///
+    /// The exponent is a power of the base, written in the same table and
+    /// with the same signs as the significand. See
+    /// [`Converter::format_float`](crate::Converter::format_float).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ExponentConflict`] if `marker` is in the table or is
+    /// another configured symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, Error, Table};
+    ///
+    /// let bin = Alphabet::new("01").unwrap()
+    ///     .with_signs('-', None).unwrap()
+    ///     .with_radix_point('.').unwrap()
+    ///     .with_exponent_marker('p').unwrap();
+    /// assert_eq!(bin.exponent_marker(), Some('p'));
+    /// assert_eq!(bin.format_float(2f64.powi(-40)).unwrap(), "1p-101000");
+    /// assert_eq!(
+    ///     Alphabet::new("0123456789abcdef").unwrap().with_exponent_marker('e'),
+    ///     Err(Error::ExponentConflict { table: Table::Alphabet, ch: 'e' })
+    /// );
+    /// ```
+    pub fn with_exponent_marker(self, marker: char) -> Result<Self> {
+        self.with_exponent_marker_for(marker, Table::Alphabet)
+    }
+
+    /// Like [`Alphabet::with_exponent_marker`], tagging any error with the given table kind.
+    pub(crate) fn with_exponent_marker_for(mut self, marker: char, kind: Table) -> Result<Self> {
+        self.exponent_marker = None;
+        if self.is_in_use(marker) {
+            return Err(Error::ExponentConflict {
+                table: kind,
+                ch: marker,
+            });
+        }
+        self.exponent_marker = Some(marker);
+        Ok(self)
+    }
+
+    /// Returns the character separating the significand from the exponent,
+    /// if scientific notation is enabled.
+    pub fn exponent_marker(&self) -> Option<char> {
+        self.exponent_marker
+    }
+
+    /// Whether `ch` is in the table or is a configured symbol
+    pub(crate) fn is_in_use(&self, ch: char) -> bool {
+        self.lookup.get(ch).is_some()
+            || [
+                self.negative_sign,
+                self.positive_sign,
+                self.radix_point,
+                self.exponent_marker,
+            ]
+            .contains(&Some(ch))
+            || self
+                .repetend_marks
+                .is_some_and(|(open, close)| ch == open || ch == close)
+    }
+
+    /// Returns the character marking negative values, if signs are enabled.
+    pub fn negative_sign(&self) -> Option<char> {
+        self.negative_sign
+    }
+
+    /// Returns the character optionally marking non-negative values, if any.
+    pub fn positive_sign(&self) -> Option<char> {
+        self.positive_sign
+    }
+
+    /// Splits a leading sign character off `input`
+    ///
+    /// Returns whether the sign is negative, the sign itself (empty if there
+    /// is none) and the rest of `input`.
+    pub(crate) fn split_sign<'a>(&self, input: &'a str) -> (bool, &'a str, &'a str) {
+        let Some(first) = input.chars().next() else {
+            return (false, "", input);
+        };
+        let negative = Some(first) == self.negative_sign;
+        if negative || Some(first) == self.positive_sign {
+            let (sign, rest) = input.split_at(first.len_utf8());
+            (negative, sign, rest)
+        } else {
+            (false, "", input)
+        }
+    }
+
+    /// Like [`Alphabet::split_sign`], for inputs that need something after
+    /// the sign
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] at the sign if nothing follows it.
+    pub(crate) fn split_sign_strict<'a>(
+        &self,
+        input: &'a str,
+    ) -> Result<(bool, &'a str, &'a str)> {
+        let (negative, sign, rest) = self.split_sign(input);
+        if !sign.is_empty() && rest.is_empty() {
+            return Err(Error::invalid_character(input, 0));
+        }
+        Ok((negative, sign, rest))
+    }
+
+    /// Returns the sign character to emit before a value with the given
+    /// sign and a non-zero magnitude
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeValue`] if `negative` is set and this
+    /// alphabet has no negative sign.
+    pub(crate) fn sign_char(&self, negative: bool) -> Result<Option<char>> {
+        if negative {
+            self.negative_sign.map(Some).ok_or(Error::NegativeValue)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the character table of this alphabet.
+    pub fn as_str(&self) -> &str {
+        &self.table
+    }
+
+    /// Returns the base of this alphabet, i.e. the number of characters in its table.
+    pub fn base(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Returns the digit value of `ch`, or `None` if it is not in the table.
+    pub fn digit(&self, ch: char) -> Option<u32> {
+        self.lookup.get(ch)
+    }
+
+    /// Returns the character for digit zero.
+    pub(crate) fn zero_char(&self) -> char {
+        self.chars[0]
+    }
+
+    /// Returns the character for `digit`, or `None` if it is not less than the base.
+    pub fn char(&self, digit: u32) -> Option<char> {
+        self.chars.get(digit as usize).copied()
+    }
+
+    /// Decodes a string into its digit values, most significant first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the table.
+    pub fn decode_digits(&self, input: &str) -> Result<Vec<u32>> {
+        let mut digits = Vec::new();
+        self.decode_digits_into(input, &mut digits)?;
+        Ok(digits)
+    }
+
+    /// Like [`Alphabet::decode_digits`], but replaces the contents of `digits`
+    pub(crate) fn decode_digits_into(&self, input: &str, digits: &mut Vec<u32>) -> Result<()> {
+        digits.clear();
+        digits.reserve(input.len());
+        self.try_for_each_digit(input, |d| {
+            digits.push(d);
+            Ok(())
+        })
+    }
+
+    /// Encodes digit values, most significant first, into a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidDigit`] if a digit is not less than the base.
+    pub fn encode_digits(&self, digits: &[u32]) -> Result<String> {
+        digits
+            .iter()
+            .enumerate()
+            .map(|(index, &digit)| {
+                self.char(digit).ok_or(Error::InvalidDigit {
+                    digit,
+                    index,
+                    base: self.base(),
+                })
+            })
+            .collect()
+    }
+
+    /// Encodes a `u64` using native arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::Alphabet;
+    ///
+    /// let base62 = Alphabet::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz").unwrap();
+    /// assert_eq!(base62.encode_u64(1_000_000), "4C92");
+    /// assert_eq!(base62.encode_u64(0), "0");
+    /// ```
+    pub fn encode_u64(&self, value: u64) -> String {
+        self.encode_u128(u128::from(value))
+    }
+
+    /// Encodes a `u128` using native arithmetic.
+    ///
+    /// Values that fit in a `u64` are encoded with 64-bit arithmetic.
+    pub fn encode_u128(&self, value: u128) -> String {
+        let mut buf = [0; 128];
+        self.render(self.native_digits(value, &mut buf))
+    }
+
+    /// Writes the digits of `value` into the end of `buf` and returns them,
+    /// most significant first
+    ///
+    /// Base is at least 2, so 128 digits always suffice.
+    pub(crate) fn native_digits<'a>(&self, value: u128, buf: &'a mut [u32; 128]) -> &'a [u32] {
+        let mut start = buf.len();
+        let mut v = value;
+        // Divide with 128-bit arithmetic only until the rest fits in a u64
+        let base = self.chars.len() as u128;
+        while v > u128::from(u64::MAX) {
+            start -= 1;
+            buf[start] = (v % base) as u32;
+            v /= base;
+        }
+        let base = self.chars.len() as u64;
+        let mut v = v as u64;
+        loop {
+            start -= 1;
+            buf[start] = (v % base) as u32;
+            v /= base;
+            if v == 0 {
+                break;
+            }
+        }
+        &buf[start..]
+    }
+
+    /// Encodes any primitive integer using native arithmetic.
+    ///
+    /// Negative values are prefixed with the negative sign.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeValue`] if `value` is negative and this
+    /// alphabet has no negative sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, Error};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap();
+    /// assert_eq!(hex.encode(255u8).unwrap(), "ff");
+    /// assert_eq!(hex.encode(-1i32), Err(Error::NegativeValue));
+    /// assert_eq!(hex.with_signs('-', None).unwrap().encode(i8::MIN).unwrap(), "-80");
+    /// ```
+    pub fn encode<T: NativeInt>(&self, value: T) -> Result<String> {
+        let (negative, magnitude) = value.to_sign_magnitude();
+        let mut out = String::new();
+        out.extend(self.sign_char(negative)?);
+        let mut buf = [0; 128];
+        self.render_into(self.native_digits(magnitude, &mut buf), &mut out);
+        Ok(out)
+    }
+
+    /// Decodes a string into a primitive integer using native arithmetic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the table, or [`Error::Overflow`] if the value does
+    /// not fit in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, Error};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap();
+    /// assert_eq!(hex.decode::<u8>("ff").unwrap(), 255);
+    /// assert_eq!(hex.decode::<i8>("ff"), Err(Error::Overflow { ty: "i8" }));
+    /// assert_eq!(hex.with_signs('-', None).unwrap().decode::<i8>("-80").unwrap(), i8::MIN);
+    /// ```
+    pub fn decode<T: NativeInt>(&self, input: &str) -> Result<T> {
+        let (negative, sign, unsigned) = self.split_sign_strict(input)?;
+        let magnitude = self
+            .decode_unsigned::<T>(unsigned)
+            .map_err(|e| e.after_prefix(sign))?;
+        T::from_sign_magnitude(negative, magnitude).ok_or(Error::Overflow { ty: T::NAME })
+    }
+
+    /// Decodes an unsigned string into a `u128`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] for characters outside the table,
+    /// including sign characters, or [`Error::Overflow`] naming `T` if the
+    /// value does not fit in a `u128`.
+    pub(crate) fn decode_unsigned<T: NativeInt>(&self, input: &str) -> Result<u128> {
+        let overflow = Error::Overflow { ty: T::NAME };
+        let base = self.chars.len() as u128;
+        let mut value: u128 = 0;
+        self.try_for_each_digit(input, |digit| {
+            value = value
+                .checked_mul(base)
+                .and_then(|v| v.checked_add(u128::from(digit)))
+                .ok_or_else(|| overflow.clone())?;
+            Ok(())
+        })?;
+        Ok(value)
+    }
+
+    /// Calls `f` with the digit of each character of `input`, in order.
+    ///
+    /// ASCII tables decode the input byte by byte; character positions are
+    /// only computed when an invalid character is found.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] carrying the position of the first
+    /// character that is not in the table, or the first error returned by `f`.
+    #[inline]
+    fn try_for_each_digit(&self, input: &str, mut f: impl FnMut(u32) -> Result<()>) -> Result<()> {
+        match &self.lookup {
+            DigitLookup::Ascii(bytes) => {
+                for (byte_offset, &b) in input.as_bytes().iter().enumerate() {
+                    let d = bytes[b as usize];
+                    if d == NO_DIGIT {
+                        // All preceding bytes were ASCII, so this is a char boundary
+                        // and the byte offset equals the char index
+                        let ch = input[byte_offset..].chars().next().unwrap();
+                        return Err(Error::InvalidCharacter {
+                            ch,
+                            byte_offset,
+                            char_index: byte_offset,
+                        });
+                    }
+                    f(u32::from(d))?;
+                }
+            }
+            DigitLookup::Sorted(_) => {
+                for (char_index, (byte_offset, ch)) in input.char_indices().enumerate() {
+                    let d = self.lookup.get(ch).ok_or(Error::InvalidCharacter {
+                        ch,
+                        byte_offset,
+                        char_index,
+                    })?;
+                    f(d)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a string into a [`BigInt`].
+    ///
+    /// Leading zero-digit characters are allowed; the empty string is zero.
+    /// If signs are enabled, the input may start with a sign character
+    /// followed by at least one digit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the table, or at the sign if no digits follow it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap();
+    /// assert_eq!(hex.parse("ff").unwrap(), BigInt::from(255u32));
+    /// ```
+    pub fn parse(&self, input: &str) -> Result<BigInt> {
+        let (negative, sign, unsigned) = self.split_sign_strict(input)?;
+        let value = self
+            .parse_unsigned(unsigned)
+            .map_err(|e| e.after_prefix(sign))?;
+        Ok(if negative { -value } else { value })
+    }
+
+    /// Like [`Alphabet::parse`], treating sign characters as invalid
+    pub(crate) fn parse_unsigned(&self, input: &str) -> Result<BigInt> {
+        let digits = self.decode_digits(input)?;
+        Ok(radix::from_digits(&digits, self.chars.len() as u32))
+    }
+
+    /// Formats a [`BigInt`] in this alphabet.
+    ///
+    /// Leading zeros are never emitted; zero is rendered as the first character.
+    /// Negative values are prefixed with the negative sign.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeValue`] if `value` is negative and this
+    /// alphabet has no negative sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt, Error};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap();
+    /// assert_eq!(hex.format(&BigInt::from(u64::MAX).pow(2)).unwrap(), "fffffffffffffffe0000000000000001");
+    /// assert_eq!(hex.format(&BigInt::from(-1i32)), Err(Error::NegativeValue));
+    /// ```
+    pub fn format(&self, value: &BigInt) -> Result<String> {
+        let mut out = String::new();
+        out.extend(self.sign_char(value.is_negative())?);
+        let digits = radix::to_digits(value.abs(), self.chars.len() as u32);
+        self.render_into(&digits, &mut out);
+        Ok(out)
+    }
+
+    /// Like [`Alphabet::format`] for a non-negative `value`, consuming it to
+    /// avoid a copy
+    pub(crate) fn format_unsigned(&self, value: BigInt) -> String {
+        debug_assert!(!value.is_negative());
+        self.render(&radix::to_digits(value, self.chars.len() as u32))
+    }
+
+    /// Map digits, each known to be less than the base, to characters
+    pub(crate) fn render(&self, digits: &[u32]) -> String {
+        let mut out = String::new();
+        self.render_into(digits, &mut out);
+        out
+    }
+
+    /// Like [`Alphabet::render`], but appends to `out`
+    pub(crate) fn render_into(&self, digits: &[u32], out: &mut String) {
+        match self.lookup {
+            DigitLookup::Ascii(_) => {
+                // SAFETY: every character in an ASCII table is a single byte
+                // below 0x80, so the pushed bytes keep `out` valid UTF-8
+                let bytes = unsafe { out.as_mut_vec() };
+                bytes.extend(digits.iter().map(|&d| self.chars[d as usize] as u8));
+            }
+            DigitLookup::Sorted(_) => {
+                out.reserve(digits.len() * self.max_char_len);
+                out.extend(digits.iter().map(|&d| self.chars[d as usize]));
+            }
+        }
+    }
+
+    /// Like [`Alphabet::render`], but writes the characters to `w`
+    pub(crate) fn write_digits(&self, digits: &[u32], w: &mut impl fmt::Write) -> fmt::Result {
+        digits.iter().try_for_each(|&d| w.write_char(self.chars[d as usize]))
+    }
+
+    /// Number of UTF-8 bytes [`Alphabet::render`] produces for `digits`
+    pub(crate) fn rendered_len(&self, digits: &[u32]) -> usize {
+        match self.lookup {
+            DigitLookup::Ascii(_) => digits.len(),
+            DigitLookup::Sorted(_) => digits
+                .iter()
+                .map(|&d| self.chars[d as usize].len_utf8())
+                .sum(),
+        }
+    }
+
+    /// Like [`Alphabet::render`], but writes UTF-8 into the start of `buf`
+    ///
+    /// `buf` must hold at least [`Alphabet::rendered_len`] bytes. Returns the
+    /// number of bytes written.
+    pub(crate) fn render_into_slice(&self, digits: &[u32], buf: &mut [u8]) -> usize {
+        let mut len = 0;
+        for &d in digits {
+            len += self.chars[d as usize].encode_utf8(&mut buf[len..]).len();
+        }
+        len
+    }
+}
+
+// Lists every field that takes part in equality, so alphabets that compare
+// unequal never look the same
+impl fmt::Debug for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Alphabet")
+            .field("table", &self.table)
+            .field("negative_sign", &self.negative_sign)
+            .field("positive_sign", &self.positive_sign)
+            .field("radix_point", &self.radix_point)
+            .field("repetend_marks", &self.repetend_marks)
+            .field("exponent_marker", &self.exponent_marker)
+            .finish()
+    }
+}
+
+// The lookup tables are derived from `table`, so it and the configured
+// symbols alone define identity.
+impl PartialEq for Alphabet {
+    fn eq(&self, other: &Self) -> bool {
+        self.table == other.table
+            && self.negative_sign == other.negative_sign
+            && self.positive_sign == other.positive_sign
+            && self.radix_point == other.radix_point
+            && self.repetend_marks == other.repetend_marks
+            && self.exponent_marker == other.exponent_marker
+    }
+}
+
+impl Eq for Alphabet {}
+
+impl Hash for Alphabet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.table.hash(state);
+        self.negative_sign.hash(state);
+        self.positive_sign.hash(state);
+        self.radix_point.hash(state);
+        self.repetend_marks.hash(state);
+        self.exponent_marker.hash(state);
+    }
+}
+
+impl FromStr for Alphabet {
+    type Err = Error;
+
+    fn from_str(table: &str) -> Result<Self> {
+        Alphabet::new(table)
+    }
+}
+
+impl TryFrom<String> for Alphabet {
+    type Error = Error;
+
+    fn try_from(table: String) -> Result<Self> {
+        Alphabet::new(table)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::converter::Converter;
+use crate::error::Error;
+use std::thread;
+
+/// Batches with fewer inputs per available thread than this are converted
+/// on the calling thread
+const PARALLEL_MIN_INPUTS_PER_THREAD: usize = 1024;
+
+/// Results of [`Converter::convert_batch`], packed into one buffer.
+///
+/// The outputs of all inputs are stored back to back in a single `String`,
+/// delimited by offsets, so a batch needs a handful of allocations in total
+/// instead of one per input. Failed inputs have an empty output and are
+/// reported by index.
+///
+/// # Examples
+///
+/// ```
+/// use anybase::{base, Converter, Error};
+///
+/// let converter = Converter::new(base::DEC, base::HEX);
+/// let batch = converter.convert_batch(["255", "x", "4096"]);
+/// assert_eq!(batch.len(), 3);
+/// assert_eq!(batch.get(0), Some(Ok("ff")));
+/// assert!(matches!(batch.get(1), Some(Err(Error::InvalidCharacter { .. }))));
+/// assert_eq!(batch.as_str(), "ff1000");
+/// assert_eq!(batch.offsets(), &[0, 2, 2, 6]);
+/// assert_eq!(batch.errors().len(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchOutput {
+    buffer: String,
+    /// `offsets[i]..offsets[i + 1]` is the output of input `i`
+    offsets: Vec<usize>,
+    /// Failed inputs, ordered by index
+    errors: Vec<(usize, Error)>,
+}
+
+impl BatchOutput {
+    fn new() -> Self {
+        BatchOutput {
+            buffer: String::new(),
+            offsets: vec![0],
+            errors: Vec::new(),
+        }
+    }
+
+    /// Returns the number of inputs, including failed ones.
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns `true` if the batch had no inputs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the output of input `index`, its error, or `None` if `index`
+    /// is out of range.
+    pub fn get(&self, index: usize) -> Option<Result<&str, &Error>> {
+        if index >= self.len() {
+            return None;
+        }
+        match self.errors.binary_search_by_key(&index, |&(i, _)| i) {
+            Ok(e) => Some(Err(&self.errors[e].1)),
+            Err(_) => Some(Ok(
+                &self.buffer[self.offsets[index]..self.offsets[index + 1]]
+            )),
+        }
+    }
+
+    /// Iterates over the results in input order.
+    pub fn iter(&self) -> impl Iterator<Item = Result<&str, &Error>> {
+        (0..self.len()).map(|i| self.get(i).unwrap())
+    }
+
+    /// Returns all outputs concatenated, in input order.
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Returns the `len() + 1` byte offsets delimiting the outputs in
+    /// [`BatchOutput::as_str`].
+    ///
+    /// The output of input `i` is `as_str()[offsets[i]..offsets[i + 1]]`.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the failed inputs as `(index, error)` pairs, ordered by index.
+    pub fn errors(&self) -> &[(usize, Error)] {
+        &self.errors
+    }
+
+    /// Returns `true` if every input was converted.
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Convert one more input and append its result
+    fn push(&mut self, converter: &Converter, input: &str) {
+        if let Err(e) = converter.convert_into(input, &mut self.buffer) {
+            self.errors.push((self.len(), e));
+        }
+        self.offsets.push(self.buffer.len());
+    }
+
+    /// Append the results of a batch that follows this one
+    fn extend(&mut self, other: BatchOutput) {
+        let (shift, count) = (self.buffer.len(), self.len());
+        self.buffer.push_str(&other.buffer);
+        self.offsets
+            .extend(other.offsets[1..].iter().map(|&o| o + shift));
+        self.errors
+            .extend(other.errors.into_iter().map(|(i, e)| (i + count, e)));
+    }
+}
+
+impl Converter {
+    /// Converts many inputs into one packed [`BatchOutput`].
+    ///
+    /// Equivalent to calling [`Converter::convert`] on each input, but all
+    /// outputs share one buffer and intermediate values reuse the same
+    /// scratch buffers. A failed input does not stop the batch; its error
+    /// is recorded by index.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The strings to convert, e.g. a slice of `&str` or `String`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter};
+    ///
+    /// let converter = Converter::new(base::HEX, base::DEC);
+    /// let ids = vec!["ff".to_string(), "100".to_string()];
+    /// let batch = converter.convert_batch(&ids);
+    /// assert!(batch.is_ok());
+    /// assert_eq!(batch.iter().collect::<Vec<_>>(), [Ok("255"), Ok("256")]);
+    /// ```
+    pub fn convert_batch<I>(&self, inputs: I) -> BatchOutput
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let inputs = inputs.into_iter();
+        let mut batch = BatchOutput::new();
+        batch.offsets.reserve(inputs.size_hint().0);
+        for input in inputs {
+            batch.push(self, input.as_ref());
+        }
+        batch
+    }
+
+    /// Like [`Converter::convert_batch`], but splits large batches across
+    /// scoped worker threads.
+    ///
+    /// One worker is started per available CPU, as reported by
+    /// [`std::thread::available_parallelism`]. Batches too small to benefit
+    /// are converted on the calling thread. The result is identical to
+    /// [`Converter::convert_batch`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter};
+    ///
+    /// let converter = Converter::new(base::DEC, base::HEX);
+    /// let inputs: Vec<String> = (0..10_000u32).map(|n| n.to_string()).collect();
+    /// let batch = converter.convert_batch_parallel(&inputs);
+    /// assert_eq!(batch, converter.convert_batch(&inputs));
+    /// assert_eq!(batch.get(9999), Some(Ok("270f")));
+    /// ```
+    pub fn convert_batch_parallel<S: AsRef<str> + Sync>(&self, inputs: &[S]) -> BatchOutput {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let threads = threads.min(inputs.len() / PARALLEL_MIN_INPUTS_PER_THREAD);
+        if threads <= 1 {
+            return self.convert_batch(inputs);
+        }
+
+        let chunk_len = inputs.len().div_ceil(threads);
+        thread::scope(|s| {
+            let workers: Vec<_> = inputs
+                .chunks(chunk_len)
+                .map(|chunk| s.spawn(move || self.convert_batch(chunk)))
+                .collect();
+            let mut batch = BatchOutput::new();
+            for worker in workers {
+                let part = worker
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e));
+                batch.extend(part);
+            }
+            batch
+        })
+    }
+}
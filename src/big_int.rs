@@ -1,20 +1,78 @@
+use crate::error::{Error, Result};
+use crate::radix::{self, Digits};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use std::str::FromStr;
+
 /// Radix for each limb in the BigInt implementation
 ///
 /// Type alias for the limb data type used in BigInt implementation
 /// 
 /// LimbType is the type used for each limb in the BigInt implementation.
+/// Every limb uses its full bit width, so the radix is 2^32.
 type LimbType = u32;
-/// Arbitrary precision integer implementation for base conversion
+
+/// Number of bits in each limb
+const LIMB_BITS: u32 = LimbType::BITS;
+
+/// Radix of each limb, i.e. the number of distinct values a limb holds
+const RADIX: u64 = 1 << LIMB_BITS;
+
+/// Limb count below which multiplication uses the schoolbook algorithm
+const KARATSUBA_THRESHOLD: usize = 48;
+
+/// Limb count from which balanced multiplication uses Toom-3 instead of Karatsuba
+const TOOM3_THRESHOLD: usize = 192;
+
+/// Limb count below which squaring uses the schoolbook algorithm
+const KARATSUBA_SQR_THRESHOLD: usize = 64;
+
+/// Limb count from which squaring uses Toom-3 instead of Karatsuba
+const TOOM3_SQR_THRESHOLD: usize = 256;
+
+/// Limb count below which division uses the schoolbook (Knuth) algorithm
+const RECURSIVE_DIV_THRESHOLD: usize = 32;
+
+/// Arbitrary precision signed integer
+///
+/// Values are stored as a sign flag and a magnitude, a vector of 32-bit
+/// "limbs" in base 2^32, least significant first. Zero is never negative, so
+/// `-0` and `0` are the same value. Multiplication switches to Karatsuba and Toom-3, with
+/// dedicated squaring, and division to
+/// recursive divide-and-conquer for large operands.
+///
+/// `BigInt` supports the usual arithmetic operators between owned and
+/// borrowed values. Like the primitive signed types, division truncates
+/// toward zero and the remainder has the sign of the dividend. Dividing by
+/// zero panics; use [`BigInt::checked_div`] and [`BigInt::checked_rem`] to
+/// handle that case.
+///
+/// [`Display`](fmt::Display) and [`FromStr`] use decimal. Use
+/// [`Alphabet::parse`](crate::Alphabet::parse) and
+/// [`Alphabet::format`](crate::Alphabet::format) for any other base.
+///
+/// # Examples
+///
+/// ```
+/// use anybase::{base, BigInt, Converter};
 ///
-/// This BigInt implementation uses a vector of "limbs" in base LIMB_RADIX
-/// to represent arbitrarily large integers. Limbs are stored in little-endian
-/// order where limbs[0] is the least significant.
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// let converter = Converter::new(base::HEX, base::DEC);
+/// let value = converter.parse("ffffffffffffffffffffffffffffffff").unwrap();
+/// let next = value + BigInt::from(1u32);
+/// assert_eq!(converter.format(&next).unwrap(), "340282366920938463463374607431768211456");
+/// assert_eq!(next.bits(), 129);
+/// assert_eq!(next, BigInt::from(2u32).pow(128));
+/// assert_eq!(BigInt::from(3u32) - BigInt::from(5u32), BigInt::from(-2i32));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct BigInt {
     /// Vector of limbs in little-endian order
     ///
-    /// Each limb represents a digit in base LIMB_RADIX.
+    /// Each limb represents a digit in base [`RADIX`].
     limbs: Vec<LimbType>,
+    /// Sign of the value; always `false` for zero
+    negative: bool,
 }
 
 impl BigInt {
@@ -25,7 +83,22 @@ impl BigInt {
     /// A new BigInt instance representing zero
     ///
     pub fn zero() -> Self {
-        BigInt { limbs: vec![0] }
+        BigInt {
+            limbs: vec![0],
+            negative: false,
+        }
+    }
+
+    /// Number of limbs this BigInt can hold without reallocating
+    pub(crate) fn limb_capacity(&self) -> usize {
+        self.limbs.capacity()
+    }
+
+    /// Reset this BigInt to zero, keeping its allocation
+    pub(crate) fn set_zero(&mut self) {
+        self.limbs.clear();
+        self.limbs.push(0);
+        self.negative = false;
     }
 
     /// Check if this BigInt is zero
@@ -37,6 +110,97 @@ impl BigInt {
         self.limbs.len() == 1 && self.limbs[0] == 0
     }
 
+    /// Check if this BigInt is less than zero
+    ///
+    /// # Returns
+    ///
+    /// true if the BigInt is negative; zero is never negative
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Absolute value of this BigInt
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    /// assert_eq!(BigInt::from(-42i32).abs(), BigInt::from(42u32));
+    /// ```
+    pub fn abs(&self) -> BigInt {
+        BigInt {
+            limbs: self.limbs.clone(),
+            negative: false,
+        }
+    }
+
+    /// Give this BigInt the sign `negative`, unless it is zero
+    fn with_sign(mut self, negative: bool) -> Self {
+        self.negative = negative && !self.is_zero();
+        self
+    }
+
+    /// Add `rhs`, negated if `negate` is set, to this BigInt
+    fn add_signed(&mut self, rhs: &BigInt, negate: bool) {
+        let rhs_negative = rhs.negative != negate;
+        if self.negative == rhs_negative {
+            add_shifted(&mut self.limbs, rhs.limb_slice(), 0);
+        } else if cmp(&self.limbs, &rhs.limbs) != Ordering::Less {
+            sub_assign(&mut self.limbs, rhs.limb_slice());
+        } else {
+            let mut limbs = rhs.limb_slice().to_vec();
+            sub_assign(&mut limbs, self.limb_slice());
+            self.limbs = limbs;
+            self.negative = rhs_negative;
+        }
+        if self.limbs.is_empty() {
+            self.limbs.push(0);
+        }
+        self.normalize();
+        if self.is_zero() {
+            self.negative = false;
+        }
+    }
+
+    /// Create a BigInt from limbs in little-endian order
+    ///
+    /// Leading zero limbs are removed; an empty vector represents zero.
+    fn from_limbs(mut limbs: Vec<LimbType>) -> Self {
+        trim(&mut limbs);
+        if limbs.is_empty() {
+            limbs.push(0);
+        }
+        BigInt {
+            limbs,
+            negative: false,
+        }
+    }
+
+    /// Check if the absolute value of this BigInt is odd
+    pub(crate) fn is_odd(&self) -> bool {
+        self.limbs[0] & 1 == 1
+    }
+
+    /// Number of limbs used by this BigInt
+    pub(crate) fn limb_len(&self) -> usize {
+        self.limbs.len()
+    }
+
+    /// Significant limbs of this BigInt; empty for zero
+    fn limb_slice(&self) -> &[LimbType] {
+        if self.is_zero() { &[] } else { &self.limbs }
+    }
+
+    /// Absolute value as a `u128`, or `None` if it does not fit
+    fn magnitude_u128(&self) -> Option<u128> {
+        (self.limbs.len() <= 4).then(|| {
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0u128, |acc, &limb| (acc << LIMB_BITS) | u128::from(limb))
+        })
+    }
+
     /// Normalize the BigInt by removing leading zeros
     ///
     /// This internal function removes unnecessary leading zero limbs
@@ -49,18 +213,15 @@ impl BigInt {
 
     /// Multiply this BigInt by a small value
     ///
+    /// The sign is kept unless the product is zero.
+    ///
     /// # Arguments
     ///
     /// * `small` - The multiplier, must fit in u32
     ///
-    /// # Panics
-    ///
-    /// This function may panic on arithmetic overflow in debug mode,
-    /// but handles it gracefully in release mode.
     pub fn mul_small(&mut self, small: u32) {
         if small == 0 {
-            self.limbs.clear();
-            self.limbs.push(0);
+            self.set_zero();
             return;
         }
         if small == 1 {
@@ -68,13 +229,12 @@ impl BigInt {
         }
         let mut carry: u64 = 0;
         for limb in &mut self.limbs {
+            // (2^32 - 1)^2 + (2^32 - 1) < 2^64, so the product cannot overflow
             let prod = (u64::from(*limb)) * u64::from(small) + carry;
-            *limb = (prod % LimbType::MAX as u64) as u32;
-            carry = prod / LimbType::MAX as u64;
+            (*limb, carry) = split(prod);
         }
-        while carry > 0 {
-            self.limbs.push((carry % LimbType::MAX as u64) as u32);
-            carry /= LimbType::MAX as u64;
+        if carry > 0 {
+            self.limbs.push(carry as LimbType);
         }
     }
 
@@ -84,24 +244,26 @@ impl BigInt {
     ///
     /// * `small` - The value to add, must fit in u32
     pub fn add_small(&mut self, small: u32) {
+        if self.negative {
+            *self += &BigInt::from(small);
+            return;
+        }
         let mut carry: u64 = u64::from(small);
         for limb in &mut self.limbs {
             if carry == 0 {
                 break;
             }
-            let sum = u64::from(*limb) + carry;
-            *limb = (sum % LimbType::MAX as u64) as u32;
-            carry = sum / LimbType::MAX as u64;
+            (*limb, carry) = split(u64::from(*limb) + carry);
         }
-        while carry > 0 {
-            self.limbs.push((carry % LimbType::MAX as u64) as u32);
-            carry /= LimbType::MAX as u64;
+        if carry > 0 {
+            self.limbs.push(carry as LimbType);
         }
     }
 
     /// Divide this BigInt by a small value and return the remainder
     ///
     /// Performs in-place division, modifying the BigInt to contain the quotient.
+    /// Like [`BigInt::div_rem`], the quotient is truncated toward zero.
     ///
     /// # Arguments
     ///
@@ -109,7 +271,7 @@ impl BigInt {
     ///
     /// # Returns
     ///
-    /// The remainder of the division operation
+    /// The remainder of the division of the absolute value
     ///
     /// # Panics
     ///
@@ -122,12 +284,1309 @@ impl BigInt {
         let mut rem: u64 = 0;
         // iterate from high limb to low limb
         for limb in self.limbs.iter_mut().rev() {
-            let v = rem * LimbType::MAX as u64 + u64::from(*limb);
+            let v = (rem << LIMB_BITS) | u64::from(*limb);
             let q = (v / u64::from(small)) as u32;
             rem = v % u64::from(small);
             *limb = q;
         }
         self.normalize();
+        if self.is_zero() {
+            self.negative = false;
+        }
         rem as u32
     }
+
+    /// Create a BigInt from bytes, most significant first
+    ///
+    /// Each limb is assembled directly from four bytes.
+    pub fn from_bytes_be(bytes: &[u8]) -> Self {
+        let limbs = bytes
+            .rchunks(4)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0, |limb: LimbType, &byte| (limb << 8) | LimbType::from(byte))
+            })
+            .collect();
+        BigInt::from_limbs(limbs)
+    }
+
+    /// Export this BigInt as bytes, most significant first
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the absolute value without leading zeros; empty for zero
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .limb_slice()
+            .iter()
+            .rev()
+            .flat_map(|limb| limb.to_be_bytes())
+            .collect();
+        let leading = bytes.iter().take_while(|&&b| b == 0).count();
+        bytes.drain(..leading);
+        bytes
+    }
+
+    /// Create a BigInt from digits in `base`, most significant first
+    ///
+    /// The digits are plain numbers, independent of any character table;
+    /// an empty slice is zero. Large inputs use the subquadratic
+    /// divide-and-conquer conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidBase`] if `base` is less than 2, or
+    /// [`Error::InvalidDigit`] if a digit is not less than `base`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    ///
+    /// // 1h 02m 03s as base-60 fields
+    /// let seconds = BigInt::from_digits(&[1, 2, 3], 60).unwrap();
+    /// assert_eq!(seconds, BigInt::from(3723u32));
+    /// ```
+    pub fn from_digits(digits: &[u32], base: u32) -> Result<BigInt> {
+        check_base(base)?;
+        if let Some(index) = digits.iter().position(|&d| d >= base) {
+            return Err(Error::InvalidDigit {
+                digit: digits[index],
+                index,
+                base: base as usize,
+            });
+        }
+        Ok(radix::from_digits(digits, base))
+    }
+
+    /// Digits of the absolute value of this BigInt in `base`, most
+    /// significant first
+    ///
+    /// Leading zeros are never emitted; zero is returned as `[0]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidBase`] if `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    ///
+    /// let value = BigInt::from(1_234_567_890u64);
+    /// assert_eq!(value.to_digits(1000).unwrap(), [1, 234, 567, 890]);
+    /// ```
+    pub fn to_digits(&self, base: u32) -> Result<Vec<u32>> {
+        check_base(base)?;
+        Ok(radix::to_digits(self.abs(), base))
+    }
+
+    /// Lazy iterator over the digits of the absolute value in `base`, least
+    /// significant first
+    ///
+    /// Yields the same digits as [`BigInt::to_digits`] in reverse order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidBase`] if `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    ///
+    /// let value = BigInt::from(1_234_567_890u64);
+    /// let mut digits = value.digits_le(1000).unwrap();
+    /// assert_eq!(digits.next(), Some(890));
+    /// assert_eq!(digits.collect::<Vec<_>>(), [567, 234, 1]);
+    /// ```
+    pub fn digits_le(&self, base: u32) -> Result<Digits> {
+        check_base(base)?;
+        Ok(Digits::new(self.abs(), base))
+    }
+
+    /// Divide this BigInt by another one
+    ///
+    /// Uses the schoolbook algorithm for small operands and recursive
+    /// divide-and-conquer division above 32 limbs.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The divisor, must be non-zero
+    ///
+    /// # Returns
+    ///
+    /// The quotient, truncated toward zero, and the remainder, which has the
+    /// sign of `self`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero (division by zero)
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        self.checked_div_rem(divisor).expect("division by zero")
+    }
+
+    /// Divide this BigInt by another one, or return `None` if `divisor` is zero
+    ///
+    /// # Returns
+    ///
+    /// The quotient and the remainder
+    pub fn checked_div_rem(&self, divisor: &BigInt) -> Option<(BigInt, BigInt)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let (q, r) = div_rem(self.limb_slice(), divisor.limb_slice());
+        Some((
+            BigInt::from_limbs(q).with_sign(self.negative != divisor.negative),
+            BigInt::from_limbs(r).with_sign(self.negative),
+        ))
+    }
+
+    /// `self / divisor`, or `None` if `divisor` is zero
+    pub fn checked_div(&self, divisor: &BigInt) -> Option<BigInt> {
+        self.checked_div_rem(divisor).map(|(q, _)| q)
+    }
+
+    /// `self % divisor`, or `None` if `divisor` is zero
+    pub fn checked_rem(&self, divisor: &BigInt) -> Option<BigInt> {
+        self.checked_div_rem(divisor).map(|(_, r)| r)
+    }
+
+    /// Raise this BigInt to the power `exp` by repeated squaring
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    /// assert_eq!(BigInt::from(10u32).pow(30).to_string(), format!("1{}", "0".repeat(30)));
+    /// assert_eq!(BigInt::zero().pow(0), BigInt::from(1u32));
+    /// assert_eq!(BigInt::from(-2i32).pow(3), BigInt::from(-8i32));
+    /// ```
+    pub fn pow(&self, mut exp: u32) -> BigInt {
+        let mut result = BigInt::from(1u32);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Number of significant bits of the absolute value; zero for zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    /// assert_eq!(BigInt::from(255u8).bits(), 8);
+    /// assert_eq!(BigInt::from(u128::MAX).bits(), 128);
+    /// assert_eq!(BigInt::zero().bits(), 0);
+    /// ```
+    pub fn bits(&self) -> u64 {
+        let limbs = self.limb_slice();
+        match limbs.last() {
+            None => 0,
+            Some(top) => {
+                (limbs.len() as u64 - 1) * u64::from(LIMB_BITS)
+                    + u64::from(LIMB_BITS - top.leading_zeros())
+            }
+        }
+    }
+
+    /// Greatest common divisor of the absolute values of two BigInts
+    ///
+    /// The result is never negative; `gcd(0, 0)` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    /// assert_eq!(BigInt::from(-12i32).gcd(&BigInt::from(18u32)), BigInt::from(6u32));
+    /// ```
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let (mut a, mut b) = (self.abs(), other.abs());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = std::mem::replace(&mut b, r);
+        }
+        a
+    }
+
+    /// Map this BigInt to a non-negative one by zigzag encoding
+    ///
+    /// Non-negative values `v` map to `2v` and negative values to `2|v| - 1`,
+    /// so values of small magnitude stay small: 0, -1, 1, -2, ... become
+    /// 0, 1, 2, 3, ...
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    /// assert_eq!(BigInt::from(-1i32).to_zigzag(), BigInt::from(1u32));
+    /// assert_eq!(BigInt::from(1i32).to_zigzag(), BigInt::from(2u32));
+    /// ```
+    pub fn to_zigzag(&self) -> BigInt {
+        let mut encoded = self.abs();
+        encoded.mul_small(2);
+        if self.negative {
+            decrement(&mut encoded.limbs);
+            encoded.normalize();
+        }
+        encoded
+    }
+
+    /// Decode a value produced by [`BigInt::to_zigzag`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeValue`] if `encoded` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::BigInt;
+    /// let value = BigInt::from(-12345i32);
+    /// assert_eq!(BigInt::from_zigzag(&value.to_zigzag()).unwrap(), value);
+    /// ```
+    pub fn from_zigzag(encoded: &BigInt) -> Result<BigInt> {
+        if encoded.negative {
+            return Err(Error::NegativeValue);
+        }
+        let odd = encoded.is_odd();
+        let mut value = encoded.clone();
+        value.div_mod_small(2);
+        if odd {
+            value.add_small(1);
+        }
+        Ok(value.with_sign(odd))
+    }
+}
+
+impl Default for BigInt {
+    /// Returns zero
+    fn default() -> Self {
+        BigInt::zero()
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => cmp(&self.limbs, &other.limbs),
+            (true, true) => cmp(&other.limbs, &self.limbs),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl AddAssign<&BigInt> for BigInt {
+    fn add_assign(&mut self, rhs: &BigInt) {
+        self.add_signed(rhs, false);
+    }
+}
+
+impl SubAssign<&BigInt> for BigInt {
+    fn sub_assign(&mut self, rhs: &BigInt) {
+        self.add_signed(rhs, true);
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Uses the schoolbook algorithm for small operands, Karatsuba
+    /// multiplication from 48 limbs and Toom-3 from 192 limbs. Multiplying a
+    /// value by itself uses the faster squaring algorithms.
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_limbs(mul(self.limb_slice(), rhs.limb_slice()))
+            .with_sign(self.negative != rhs.negative)
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Truncates toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// The remainder has the sign of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        let negative = !self.negative;
+        self.with_sign(negative)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+/// Implement an operator for all owned and borrowed operand combinations,
+/// based on its in-place `&BigInt` form
+macro_rules! forward_from_assign {
+    ($($Op:ident $op:ident $OpAssign:ident $op_assign:ident),*) => {$(
+        impl $Op<&BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $op(mut self, rhs: &BigInt) -> BigInt {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+
+        impl $Op<BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: BigInt) -> BigInt {
+                self.$op(&rhs)
+            }
+        }
+
+        impl $Op<&BigInt> for &BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: &BigInt) -> BigInt {
+                self.clone().$op(rhs)
+            }
+        }
+
+        impl $Op<BigInt> for &BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: BigInt) -> BigInt {
+                self.clone().$op(&rhs)
+            }
+        }
+
+        impl $OpAssign<BigInt> for BigInt {
+            fn $op_assign(&mut self, rhs: BigInt) {
+                self.$op_assign(&rhs);
+            }
+        }
+    )*};
+}
+
+/// Implement an operator for all owned and borrowed operand combinations,
+/// based on its `&BigInt op &BigInt` form
+macro_rules! forward_from_ref {
+    ($($Op:ident $op:ident $OpAssign:ident $op_assign:ident),*) => {$(
+        impl $Op<&BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: &BigInt) -> BigInt {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl $Op<BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: BigInt) -> BigInt {
+                (&self).$op(&rhs)
+            }
+        }
+
+        impl $Op<BigInt> for &BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: BigInt) -> BigInt {
+                self.$op(&rhs)
+            }
+        }
+
+        impl $OpAssign<&BigInt> for BigInt {
+            fn $op_assign(&mut self, rhs: &BigInt) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+
+        impl $OpAssign<BigInt> for BigInt {
+            fn $op_assign(&mut self, rhs: BigInt) {
+                *self = (&*self).$op(&rhs);
+            }
+        }
+    )*};
+}
+
+forward_from_assign!(Add add AddAssign add_assign, Sub sub SubAssign sub_assign);
+forward_from_ref!(Mul mul MulAssign mul_assign, Div div DivAssign div_assign, Rem rem RemAssign rem_assign);
+
+macro_rules! impl_primitive_conversions {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigInt {
+            fn from(value: $t) -> Self {
+                let value = value as u128;
+                BigInt::from_limbs((0..4).map(|i| (value >> (LIMB_BITS * i)) as LimbType).collect())
+            }
+        }
+
+        impl TryFrom<&BigInt> for $t {
+            type Error = Error;
+
+            /// # Errors
+            ///
+            /// Returns [`Error::Overflow`] if the value is negative or does
+            /// not fit.
+            fn try_from(value: &BigInt) -> Result<Self> {
+                let overflow = Error::Overflow { ty: stringify!($t) };
+                if value.negative {
+                    return Err(overflow);
+                }
+                value
+                    .magnitude_u128()
+                    .and_then(|v| <$t>::try_from(v).ok())
+                    .ok_or(overflow)
+            }
+        }
+
+        impl TryFrom<BigInt> for $t {
+            type Error = Error;
+
+            /// # Errors
+            ///
+            /// Returns [`Error::Overflow`] if the value does not fit.
+            fn try_from(value: BigInt) -> Result<Self> {
+                <$t>::try_from(&value)
+            }
+        }
+    )*};
+}
+
+impl_primitive_conversions!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_signed_conversions {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigInt {
+            fn from(value: $t) -> Self {
+                BigInt::from(value.unsigned_abs()).with_sign(value < 0)
+            }
+        }
+
+        impl TryFrom<&BigInt> for $t {
+            type Error = Error;
+
+            /// # Errors
+            ///
+            /// Returns [`Error::Overflow`] if the value does not fit.
+            fn try_from(value: &BigInt) -> Result<Self> {
+                let overflow = Error::Overflow { ty: stringify!($t) };
+                let magnitude = value.magnitude_u128().ok_or(overflow.clone())?;
+                if value.negative {
+                    // Also covers MIN, whose magnitude is MAX + 1
+                    0i128
+                        .checked_sub_unsigned(magnitude)
+                        .and_then(|v| <$t>::try_from(v).ok())
+                        .ok_or(overflow)
+                } else {
+                    <$t>::try_from(magnitude).map_err(|_| overflow)
+                }
+            }
+        }
+
+        impl TryFrom<BigInt> for $t {
+            type Error = Error;
+
+            /// # Errors
+            ///
+            /// Returns [`Error::Overflow`] if the value does not fit.
+            fn try_from(value: BigInt) -> Result<Self> {
+                <$t>::try_from(&value)
+            }
+        }
+    )*};
+}
+
+impl_signed_conversions!(i8, i16, i32, i64, i128, isize);
+
+impl fmt::Display for BigInt {
+    /// Formats the value in decimal
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = radix::to_digits(self.abs(), 10);
+        let s: String = digits
+            .iter()
+            .map(|&d| char::from(b'0' + d as u8))
+            .collect();
+        f.pad_integral(!self.negative, "", &s)
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BigInt({})", self)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = Error;
+
+    /// Parses a decimal string with an optional leading `-` or `+`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyInput`] if `s` is empty, or
+    /// [`Error::InvalidCharacter`] if it contains any other character than
+    /// `0`-`9` after the sign, or at the sign if no digits follow it.
+    fn from_str(s: &str) -> Result<Self> {
+        if s.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        let (negative, sign_len) = match s.as_bytes().first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let unsigned = &s[sign_len..];
+        if sign_len > 0 && unsigned.is_empty() {
+            return Err(Error::invalid_character(s, 0));
+        }
+        if let Some((byte_offset, ch)) =
+            unsigned.char_indices().find(|(_, ch)| !ch.is_ascii_digit())
+        {
+            return Err(Error::InvalidCharacter {
+                ch,
+                byte_offset: sign_len + byte_offset,
+                char_index: sign_len + unsigned[..byte_offset].chars().count(),
+            });
+        }
+        let digits: Vec<u32> = unsigned.bytes().map(|b| u32::from(b - b'0')).collect();
+        Ok(radix::from_digits(&digits, 10).with_sign(negative))
+    }
+}
+
+/// Reject digit bases below 2
+fn check_base(base: u32) -> Result<()> {
+    if base < 2 {
+        return Err(Error::InvalidBase {
+            base: base as usize,
+        });
+    }
+    Ok(())
+}
+
+/// Split a double-width value into its low limb and the carry
+#[inline]
+fn split(v: u64) -> (LimbType, u64) {
+    (v as LimbType, v >> LIMB_BITS)
+}
+
+/// `a << shift` for `shift < LIMB_BITS`, as a new limb vector
+fn shl_bits(a: &[LimbType], shift: u32) -> Vec<LimbType> {
+    let mut out = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for &limb in a {
+        let v = (u64::from(limb) << shift) | carry;
+        out.push(v as LimbType);
+        carry = v >> LIMB_BITS;
+    }
+    out.push(carry as LimbType);
+    trim(&mut out);
+    out
+}
+
+/// `a >>= shift` in place for `shift < LIMB_BITS`
+fn shr_bits(a: &mut Vec<LimbType>, shift: u32) {
+    if shift > 0 {
+        let mut carry = 0;
+        for limb in a.iter_mut().rev() {
+            let v = (carry << LIMB_BITS) | u64::from(*limb);
+            *limb = (v >> shift) as LimbType;
+            carry = v & ((1 << shift) - 1);
+        }
+    }
+    trim(a);
+}
+
+/// Remove leading (most significant) zero limbs; zero becomes empty
+fn trim(v: &mut Vec<LimbType>) {
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+}
+
+/// Slice without its leading zero limbs
+fn trimmed(v: &[LimbType]) -> &[LimbType] {
+    let len = v.iter().rposition(|&l| l != 0).map_or(0, |i| i + 1);
+    &v[..len]
+}
+
+/// Compare two limb slices by value
+fn cmp(a: &[LimbType], b: &[LimbType]) -> Ordering {
+    let (a, b) = (trimmed(a), trimmed(b));
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// `x += y * RADIX^shift`, growing `x` as needed
+fn add_shifted(x: &mut Vec<LimbType>, y: &[LimbType], shift: usize) {
+    let y = trimmed(y);
+    if y.is_empty() {
+        return;
+    }
+    if x.len() < shift + y.len() {
+        x.resize(shift + y.len(), 0);
+    }
+    let mut carry = 0;
+    for (xi, &yi) in x[shift..].iter_mut().zip(y) {
+        (*xi, carry) = split(u64::from(*xi) + u64::from(yi) + carry);
+    }
+    let mut i = shift + y.len();
+    while carry != 0 {
+        if i == x.len() {
+            x.push(0);
+        }
+        (x[i], carry) = split(u64::from(x[i]) + carry);
+        i += 1;
+    }
+}
+
+/// `x -= y`, requires `x >= y`
+fn sub_assign(x: &mut Vec<LimbType>, y: &[LimbType]) {
+    let y = trimmed(y);
+    let mut borrow = false;
+    for (i, limb) in x.iter_mut().enumerate() {
+        if i >= y.len() && !borrow {
+            break;
+        }
+        let (d, b1) = limb.overflowing_sub(y.get(i).copied().unwrap_or(0));
+        let (d, b2) = d.overflowing_sub(LimbType::from(borrow));
+        *limb = d;
+        borrow = b1 || b2;
+    }
+    debug_assert!(!borrow, "subtraction underflow");
+    trim(x);
+}
+
+/// `x -= 1`, requires `x > 0`
+fn decrement(x: &mut Vec<LimbType>) {
+    sub_assign(x, &[1]);
+}
+
+/// Product of two limb slices
+fn mul(a: &[LimbType], b: &[LimbType]) -> Vec<LimbType> {
+    let (a, b) = (trimmed(a), trimmed(b));
+    if std::ptr::eq(a, b) {
+        return sqr(a);
+    }
+    // Keep `a` as the longer operand
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if b.len() < KARATSUBA_THRESHOLD {
+        return schoolbook_mul(a, b);
+    }
+    if 2 * b.len() <= a.len() {
+        // Unbalanced operands: multiply `b` by slices of `a` of its own size
+        let mut out = Vec::with_capacity(a.len() + b.len());
+        for (i, chunk) in a.chunks(b.len()).enumerate() {
+            add_shifted(&mut out, &mul(chunk, b), i * b.len());
+        }
+        return out;
+    }
+    if b.len() < TOOM3_THRESHOLD {
+        return karatsuba_mul(a, b);
+    }
+    toom3_mul(a, b)
+}
+
+/// Square of a limb slice
+fn sqr(a: &[LimbType]) -> Vec<LimbType> {
+    let a = trimmed(a);
+    if a.len() < KARATSUBA_SQR_THRESHOLD {
+        schoolbook_sqr(a)
+    } else if a.len() < TOOM3_SQR_THRESHOLD {
+        karatsuba_sqr(a)
+    } else {
+        toom3_sqr(a)
+    }
+}
+
+/// Quadratic schoolbook multiplication
+fn schoolbook_mul(a: &[LimbType], b: &[LimbType]) -> Vec<LimbType> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![0; a.len() + b.len()];
+    for (i, &ai) in a.iter().enumerate() {
+        if ai == 0 {
+            continue;
+        }
+        let mut carry = 0;
+        for (j, &bj) in b.iter().enumerate() {
+            let t = u64::from(ai) * u64::from(bj) + u64::from(out[i + j]) + carry;
+            (out[i + j], carry) = split(t);
+        }
+        out[i + b.len()] = carry as LimbType;
+    }
+    trim(&mut out);
+    out
+}
+
+/// Quadratic schoolbook squaring
+///
+/// Each cross product `a[i] * a[j]` with `i != j` is computed once and doubled.
+fn schoolbook_sqr(a: &[LimbType]) -> Vec<LimbType> {
+    let n = a.len();
+    let mut out = vec![0; 2 * n];
+    for i in 0..n {
+        let mut carry = 0;
+        for j in i + 1..n {
+            let t = u64::from(a[i]) * u64::from(a[j]) + u64::from(out[i + j]) + carry;
+            (out[i + j], carry) = split(t);
+        }
+        out[i + n] = carry as LimbType;
+    }
+    // Double the cross products; their sum is below a^2 / 2, so nothing is lost
+    let mut high = 0;
+    for limb in out.iter_mut() {
+        (*limb, high) = (*limb << 1 | high, *limb >> (LIMB_BITS - 1));
+    }
+    let mut carry = 0;
+    for (i, &ai) in a.iter().enumerate() {
+        let (lo, hi) = split(u64::from(ai) * u64::from(ai));
+        (out[2 * i], carry) = split(u64::from(out[2 * i]) + u64::from(lo) + carry);
+        (out[2 * i + 1], carry) = split(u64::from(out[2 * i + 1]) + hi + carry);
+    }
+    trim(&mut out);
+    out
+}
+
+/// Karatsuba multiplication, requires `a.len() >= b.len() > a.len() / 2`
+fn karatsuba_mul(a: &[LimbType], b: &[LimbType]) -> Vec<LimbType> {
+    let half = a.len().div_ceil(2);
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half.min(b.len()));
+
+    let z0 = mul(a0, b0);
+    let z2 = mul(a1, b1);
+    // z1 = (a0 + a1)(b0 + b1) - z0 - z2 = a0*b1 + a1*b0
+    let mut sum_a = a0.to_vec();
+    add_shifted(&mut sum_a, a1, 0);
+    let mut sum_b = b0.to_vec();
+    add_shifted(&mut sum_b, b1, 0);
+    let mut z1 = mul(&sum_a, &sum_b);
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut out = Vec::with_capacity(a.len() + b.len());
+    add_shifted(&mut out, &z0, 0);
+    add_shifted(&mut out, &z1, half);
+    add_shifted(&mut out, &z2, 2 * half);
+    out
+}
+
+/// Karatsuba squaring: three half-size squares instead of four products
+fn karatsuba_sqr(a: &[LimbType]) -> Vec<LimbType> {
+    let half = a.len().div_ceil(2);
+    let (a0, a1) = a.split_at(half);
+
+    let z0 = sqr(a0);
+    let z2 = sqr(a1);
+    // z1 = (a0 + a1)^2 - z0 - z2 = 2 * a0 * a1
+    let mut sum = a0.to_vec();
+    add_shifted(&mut sum, a1, 0);
+    let mut z1 = sqr(&sum);
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut out = Vec::with_capacity(2 * a.len());
+    add_shifted(&mut out, &z0, 0);
+    add_shifted(&mut out, &z1, half);
+    add_shifted(&mut out, &z2, 2 * half);
+    out
+}
+
+/// Toom-3 multiplication, requires `a.len() >= b.len() > a.len() / 2`
+///
+/// Splits both operands into three parts of `k` limbs and multiplies their
+/// values at five points, so five products of a third of the size replace
+/// the nine of schoolbook splitting.
+fn toom3_mul(a: &[LimbType], b: &[LimbType]) -> Vec<LimbType> {
+    let k = a.len().div_ceil(3);
+    let pa = toom3_evaluate(a, k);
+    let pb = toom3_evaluate(b, k);
+    let products = std::array::from_fn(|i| pa[i].mul(&pb[i]));
+    toom3_interpolate(products, k, a.len() + b.len())
+}
+
+/// Toom-3 squaring: five squares of a third of the size
+fn toom3_sqr(a: &[LimbType]) -> Vec<LimbType> {
+    let k = a.len().div_ceil(3);
+    let pa = toom3_evaluate(a, k);
+    let squares = pa.map(|p| Signed {
+        negative: false,
+        magnitude: sqr(&p.magnitude),
+    });
+    toom3_interpolate(squares, k, 2 * a.len())
+}
+
+/// Values of `a0 + a1 x + a2 x^2` at `x = 0, 1, -1, -2, ∞`, where `a` is
+/// split into parts `a0, a1, a2` of `k` limbs, least significant first
+fn toom3_evaluate(a: &[LimbType], k: usize) -> [Signed; 5] {
+    let part = |i: usize| Signed::from(&a[(i * k).min(a.len())..((i + 1) * k).min(a.len())]);
+    let (a0, a1, a2) = (part(0), part(1), part(2));
+    let p0 = a0.add(&a2);
+    let p1 = p0.add(&a1);
+    let m1 = p0.sub(&a1);
+    // p(-2) = (p(-1) + a2) * 2 - a0
+    let mut m2 = m1.add(&a2);
+    m2.magnitude = shl_bits(&m2.magnitude, 1);
+    let m2 = m2.sub(&a0);
+    [a0, p1, m1, m2, a2]
+}
+
+/// Recover the product from its values at `0, 1, -1, -2, ∞`
+///
+/// Uses Bodrato's interpolation sequence; every division is exact.
+fn toom3_interpolate(r: [Signed; 5], k: usize, len: usize) -> Vec<LimbType> {
+    let [r0, r1, rm1, rm2, rinf] = r;
+    let mut c3 = rm2.sub(&r1);
+    c3.div_exact_small(3);
+    let mut c1 = r1.sub(&rm1);
+    c1.div_exact_small(2);
+    let c2 = rm1.sub(&r0);
+    let mut c3 = c2.sub(&c3);
+    c3.div_exact_small(2);
+    let mut twice_inf = rinf.clone();
+    twice_inf.magnitude = shl_bits(&twice_inf.magnitude, 1);
+    let c3 = c3.add(&twice_inf);
+    let c2 = c2.add(&c1).sub(&rinf);
+    let c1 = c1.sub(&c3);
+
+    let mut out = Vec::with_capacity(len);
+    for (i, c) in [r0, c1, c2, c3, rinf].into_iter().enumerate() {
+        // Every coefficient of a product of non-negative polynomials is non-negative
+        debug_assert!(!c.negative || c.magnitude.is_empty());
+        add_shifted(&mut out, &c.magnitude, i * k);
+    }
+    trim(&mut out);
+    out
+}
+
+/// Signed limb vector for the intermediate values of Toom-3
+#[derive(Clone)]
+struct Signed {
+    negative: bool,
+    /// Trimmed magnitude; empty for zero
+    magnitude: Vec<LimbType>,
+}
+
+impl Signed {
+    fn from(limbs: &[LimbType]) -> Self {
+        Signed {
+            negative: false,
+            magnitude: trimmed(limbs).to_vec(),
+        }
+    }
+
+    fn add(&self, other: &Signed) -> Signed {
+        if self.negative == other.negative {
+            let mut magnitude = self.magnitude.clone();
+            add_shifted(&mut magnitude, &other.magnitude, 0);
+            return Signed {
+                negative: self.negative,
+                magnitude,
+            };
+        }
+        // Opposite signs: subtract the smaller magnitude from the larger one
+        let (larger, smaller) = match cmp(&self.magnitude, &other.magnitude) {
+            Ordering::Less => (other, self),
+            _ => (self, other),
+        };
+        let mut magnitude = larger.magnitude.clone();
+        sub_assign(&mut magnitude, &smaller.magnitude);
+        Signed {
+            negative: larger.negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    fn sub(&self, other: &Signed) -> Signed {
+        let negated = Signed {
+            negative: !other.negative && !other.magnitude.is_empty(),
+            magnitude: other.magnitude.clone(),
+        };
+        self.add(&negated)
+    }
+
+    fn mul(&self, other: &Signed) -> Signed {
+        let magnitude = mul(&self.magnitude, &other.magnitude);
+        Signed {
+            negative: self.negative != other.negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    /// Divide by `d`, which must divide the value exactly
+    fn div_exact_small(&mut self, d: u32) {
+        let mut rem: u64 = 0;
+        for limb in self.magnitude.iter_mut().rev() {
+            let v = (rem << LIMB_BITS) | u64::from(*limb);
+            *limb = (v / u64::from(d)) as LimbType;
+            rem = v % u64::from(d);
+        }
+        debug_assert_eq!(rem, 0, "inexact division");
+        trim(&mut self.magnitude);
+    }
+}
+
+/// Quotient and remainder of two limb slices, `b` must be non-zero
+fn div_rem(a: &[LimbType], b: &[LimbType]) -> (Vec<LimbType>, Vec<LimbType>) {
+    let (a, b) = (trimmed(a), trimmed(b));
+    if cmp(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() < RECURSIVE_DIV_THRESHOLD || a.len() - b.len() < RECURSIVE_DIV_THRESHOLD {
+        return knuth_div_rem(a, b);
+    }
+
+    // Normalize so that the top bit of the divisor is set
+    let shift = b.last().unwrap().leading_zeros();
+    let b = shl_bits(b, shift);
+    let a = shl_bits(a, shift);
+    let n = b.len();
+
+    // Divide blocks of `n` limbs from the top, so each step has a quotient
+    // of at most `n` limbs as the recursive algorithm requires
+    let mut q = Vec::new();
+    let mut r: Vec<LimbType> = Vec::new();
+    let mut end = a.len();
+    while end > 0 {
+        let start = end.saturating_sub(n);
+        let mut cur = a[start..end].to_vec();
+        add_shifted(&mut cur, &r, end - start);
+        let (qi, ri) = recursive_div_rem(&cur, &b);
+        add_shifted(&mut q, &qi, start);
+        r = ri;
+        end = start;
+    }
+
+    // Undo the normalization of the remainder
+    shr_bits(&mut r, shift);
+    trim(&mut q);
+    (q, r)
+}
+
+/// Recursive division (Burnikel-Ziegler, as given in Brent & Zimmermann,
+/// "Modern Computer Arithmetic", algorithm 1.8)
+///
+/// Requires `b` normalized (top bit set) and `a < b * RADIX^n`
+/// where `n = b.len()`.
+fn recursive_div_rem(a: &[LimbType], b: &[LimbType]) -> (Vec<LimbType>, Vec<LimbType>) {
+    let n = b.len();
+    if a.len() <= n {
+        return knuth_div_rem(a, b);
+    }
+    let m = a.len() - n;
+    if m < RECURSIVE_DIV_THRESHOLD {
+        return knuth_div_rem(a, b);
+    }
+    let k = m / 2;
+    let (b0, b1) = b.split_at(k);
+
+    // (q1, r1) = (a / RADIX^2k) divmod b1
+    let (mut q1, r1) = recursive_div_rem(&a[2 * k..], b1);
+    // a' = r1 * RADIX^2k + (a mod RADIX^2k) - q1 * b0 * RADIX^k
+    let mut a1 = a[..2 * k].to_vec();
+    add_shifted(&mut a1, &r1, 2 * k);
+    let mut t = Vec::new();
+    add_shifted(&mut t, &mul(&q1, b0), k);
+    while cmp(&a1, &t) == Ordering::Less {
+        decrement(&mut q1);
+        add_shifted(&mut a1, b, k);
+    }
+    sub_assign(&mut a1, &t);
+
+    // (q0, r0) = (a' / RADIX^k) divmod b1
+    let high = a1.get(k..).unwrap_or(&[]);
+    let (mut q0, r0) = recursive_div_rem(high, b1);
+    // a'' = r0 * RADIX^k + (a' mod RADIX^k) - q0 * b0
+    let mut a2 = a1[..k.min(a1.len())].to_vec();
+    add_shifted(&mut a2, &r0, k);
+    let t = mul(&q0, b0);
+    while cmp(&a2, &t) == Ordering::Less {
+        decrement(&mut q0);
+        add_shifted(&mut a2, b, 0);
+    }
+    sub_assign(&mut a2, &t);
+
+    let mut q = q0;
+    add_shifted(&mut q, &q1, k);
+    (q, a2)
+}
+
+/// Schoolbook long division (Knuth, TAOCP vol. 2, algorithm D)
+fn knuth_div_rem(a: &[LimbType], b: &[LimbType]) -> (Vec<LimbType>, Vec<LimbType>) {
+    let (a, b) = (trimmed(a), trimmed(b));
+    if cmp(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let mut q = BigInt::from_limbs(a.to_vec());
+        let r = q.div_mod_small(b[0]);
+        let mut r = vec![r];
+        trim(&mut r);
+        let mut q = q.limbs;
+        trim(&mut q);
+        return (q, r);
+    }
+
+    // Normalize so that the top bit of the divisor is set
+    let n = b.len();
+    let shift = b[n - 1].leading_zeros();
+    let v = shl_bits(b, shift);
+    let mut u = shl_bits(a, shift);
+    u.resize(a.len() + 1, 0);
+    let m = a.len() - n;
+
+    let v_top = u64::from(v[n - 1]);
+    let v_next = u64::from(v[n - 2]);
+    let mut q = vec![0; m + 1];
+    for j in (0..=m).rev() {
+        // Estimate the quotient limb from the top two limbs
+        let num = (u64::from(u[j + n]) << LIMB_BITS) | u64::from(u[j + n - 1]);
+        let mut qhat = num / v_top;
+        let mut rhat = num % v_top;
+        while qhat >= RADIX || qhat * v_next > ((rhat << LIMB_BITS) | u64::from(u[j + n - 2])) {
+            qhat -= 1;
+            rhat += v_top;
+            if rhat >= RADIX {
+                break;
+            }
+        }
+
+        // u[j..=j+n] -= qhat * v
+        let mut carry = 0;
+        let mut borrow = false;
+        for i in 0..n {
+            let p;
+            (p, carry) = split(qhat * u64::from(v[i]) + carry);
+            let (d, b1) = u[i + j].overflowing_sub(p);
+            let (d, b2) = d.overflowing_sub(LimbType::from(borrow));
+            u[i + j] = d;
+            borrow = b1 || b2;
+        }
+        // carry < 2^32 and the top limb absorbs the borrow, so this fits in i64
+        let top = i64::from(u[j + n]) - carry as i64 - i64::from(borrow);
+        u[j + n] = top as LimbType;
+        if top < 0 {
+            // The estimate was one too large: add the divisor back
+            qhat -= 1;
+            let mut carry = 0;
+            for i in 0..n {
+                (u[i + j], carry) = split(u64::from(u[i + j]) + u64::from(v[i]) + carry);
+            }
+            // The final carry cancels the borrow taken above
+            u[j + n] = split(u64::from(u[j + n]) + carry).0;
+        }
+        q[j] = qhat as LimbType;
+    }
+
+    // Undo the normalization of the remainder
+    u.truncate(n);
+    shr_bits(&mut u, shift);
+    trim(&mut q);
+    (q, u)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::Rng;
+
+    /// Random BigInt with `len` limbs, biased towards extreme limb values
+    fn random_big(rng: &mut Rng, len: usize) -> BigInt {
+        let limbs = (0..len)
+            .map(|_| match rng.next() % 4 {
+                0 => 0,
+                1 => (RADIX - 1) as LimbType,
+                _ => (rng.next() % RADIX) as LimbType,
+            })
+            .collect();
+        BigInt::from_limbs(limbs)
+    }
+
+    fn from_u128(v: u128) -> BigInt {
+        BigInt::from(v)
+    }
+
+    fn to_u128(b: &BigInt) -> u128 {
+        u128::try_from(b).unwrap()
+    }
+
+    /// Random u128 with a random bit length, so small values are common too
+    fn random_u128(rng: &mut Rng, max_bits: u32) -> u128 {
+        let v = (u128::from(rng.next()) << 64) | u128::from(rng.next());
+        let bits = (rng.next() % u64::from(max_bits + 1)) as u32;
+        if bits == 0 { 0 } else { v >> (128 - bits) }
+    }
+
+    #[test]
+    fn test_differential_against_u128() {
+        let mut rng = Rng(0x0123456789abcdef);
+        for _ in 0..20_000 {
+            let a = random_u128(&mut rng, 96);
+            let small = match rng.next() % 3 {
+                0 => u32::MAX,
+                1 => (rng.next() % 64) as u32 + 1,
+                _ => rng.next() as u32 | 1,
+            };
+
+            let mut big = from_u128(a);
+            big.mul_small(small);
+            assert_eq!(to_u128(&big), a * u128::from(small));
+
+            let mut big = from_u128(a);
+            big.add_small(small);
+            assert_eq!(to_u128(&big), a + u128::from(small));
+
+            let mut big = from_u128(a);
+            let rem = big.div_mod_small(small);
+            assert_eq!(to_u128(&big), a / u128::from(small));
+            assert_eq!(u128::from(rem), a % u128::from(small));
+
+            let x = random_u128(&mut rng, 64);
+            let y = random_u128(&mut rng, 64);
+            assert_eq!(to_u128(&(from_u128(x) * from_u128(y))), x * y);
+
+            assert_eq!(to_u128(&(from_u128(x) + from_u128(y))), x + y);
+
+            let n = random_u128(&mut rng, 128);
+            let d = random_u128(&mut rng, 128).max(1);
+            let (q, r) = from_u128(n).div_rem(&from_u128(d));
+            assert_eq!((to_u128(&q), to_u128(&r)), (n / d, n % d));
+            assert_eq!(from_u128(n).cmp(&from_u128(d)), n.cmp(&d));
+
+            let bytes = n.to_be_bytes();
+            let leading = bytes.iter().take_while(|&&b| b == 0).count();
+            assert_eq!(BigInt::from_bytes_be(&bytes), from_u128(n));
+            assert_eq!(from_u128(n).to_bytes_be(), &bytes[leading..]);
+        }
+    }
+
+    #[test]
+    fn test_mul_matches_schoolbook() {
+        let mut rng = Rng(0x9e3779b97f4a7c15);
+        let sizes = [(1, 1), (40, 33), (100, 100), (150, 64), (300, 7), (257, 190), (48, 48), (192, 192), (400, 300), (1000, 999)];
+        for &(la, lb) in &sizes {
+            let a = random_big(&mut rng, la);
+            let b = random_big(&mut rng, lb);
+            let expected = BigInt::from_limbs(schoolbook_mul(a.limb_slice(), b.limb_slice()));
+            assert_eq!(&a * &b, expected, "{}x{} limbs", la, lb);
+        }
+    }
+
+    #[test]
+    fn test_algorithms_match_schoolbook() {
+        let mut rng = Rng(0xa0761d6478bd642f);
+        for _ in 0..300 {
+            let la = (rng.next() % 60) as usize + 2;
+            let lb = la / 2 + 1 + (rng.next() % (la - la / 2) as u64) as usize;
+            let a = random_big(&mut rng, la);
+            let b = random_big(&mut rng, lb);
+            let (a, b) = (a.limb_slice(), b.limb_slice());
+            let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+            if b.len() * 2 <= a.len() {
+                continue;
+            }
+            let expected = BigInt::from_limbs(schoolbook_mul(a, b));
+            let label = format!("{}x{} limbs", a.len(), b.len());
+            assert_eq!(BigInt::from_limbs(karatsuba_mul(a, b)), expected, "karatsuba {}", label);
+            assert_eq!(BigInt::from_limbs(toom3_mul(a, b)), expected, "toom3 {}", label);
+
+            let square = BigInt::from_limbs(schoolbook_mul(a, a));
+            assert_eq!(BigInt::from_limbs(schoolbook_sqr(a)), square, "schoolbook sqr {}", label);
+            assert_eq!(BigInt::from_limbs(karatsuba_sqr(a)), square, "karatsuba sqr {}", label);
+            assert_eq!(BigInt::from_limbs(toom3_sqr(a)), square, "toom3 sqr {}", label);
+        }
+    }
+
+    #[test]
+    fn test_sqr_matches_schoolbook() {
+        let mut rng = Rng(0xe7037ed1a0b428db);
+        for len in [1, 2, 63, 64, 100, 255, 256, 500, 1201] {
+            let a = random_big(&mut rng, len);
+            let expected = BigInt::from_limbs(schoolbook_mul(a.limb_slice(), a.limb_slice()));
+            assert_eq!(&a * &a, expected, "{} limbs", len);
+            assert_eq!(a.pow(2), expected, "{} limbs", len);
+        }
+        let max = BigInt::from_limbs(vec![LimbType::MAX; 300]);
+        let expected = BigInt::from_limbs(schoolbook_mul(max.limb_slice(), max.limb_slice()));
+        assert_eq!(&max * &max, expected);
+    }
+
+    #[test]
+    fn test_div_rem_identity() {
+        let mut rng = Rng(0x2545f4914f6cdd1d);
+        for &(la, lb) in &[(2, 1), (5, 3), (70, 33), (200, 100), (300, 40), (400, 130), (90, 90)] {
+            for _ in 0..4 {
+                let a = random_big(&mut rng, la);
+                let b = random_big(&mut rng, lb);
+                if b.is_zero() {
+                    continue;
+                }
+                let (q, r) = a.div_rem(&b);
+                assert!(r < b);
+                assert_eq!(&q * &b + &r, a, "{}/{} limbs", la, lb);
+            }
+        }
+    }
+
+    #[test]
+    fn test_div_rem_exact_boundaries() {
+        let mut rng = Rng(0xdeadbeefcafebabe);
+        for &(lq, lb) in &[(40, 40), (100, 50), (64, 120)] {
+            let q = random_big(&mut rng, lq);
+            let b = random_big(&mut rng, lb);
+            let product = &q * &b;
+            assert_eq!(product.div_rem(&b), (q.clone(), BigInt::zero()));
+
+            // product - 1 = (q - 1) * b + (b - 1)
+            let mut below = product.limbs.clone();
+            decrement(&mut below);
+            let (q2, r2) = BigInt::from_limbs(below).div_rem(&b);
+            let mut q_minus_one = q.limbs.clone();
+            decrement(&mut q_minus_one);
+            let mut b_minus_one = b.limbs.clone();
+            decrement(&mut b_minus_one);
+            assert_eq!(q2, BigInt::from_limbs(q_minus_one));
+            assert_eq!(r2, BigInt::from_limbs(b_minus_one));
+        }
+    }
 }
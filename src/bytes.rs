@@ -0,0 +1,97 @@
+use crate::alphabet::Alphabet;
+use crate::big_int::BigInt;
+use crate::error::Result;
+
+/// Byte order used to interpret byte slices as numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// The first byte is the most significant (network order, as in base58)
+    #[default]
+    BigEndian,
+    /// The first byte is the least significant
+    LittleEndian,
+}
+
+impl Alphabet {
+    /// Encodes a byte slice, interpreting it as a big-endian number.
+    ///
+    /// Each leading zero byte is encoded as one leading zero-digit character,
+    /// as base58 and base-x do, so [`Alphabet::decode_bytes`] restores the
+    /// input exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::Alphabet;
+    ///
+    /// let base58 = Alphabet::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz").unwrap();
+    /// assert_eq!(base58.encode_bytes(b"hello world"), "StV1DL6CwTryKyV");
+    /// assert_eq!(base58.encode_bytes(&[0, 0, 1]), "112");
+    /// assert_eq!(base58.decode_bytes("112").unwrap(), vec![0, 0, 1]);
+    /// ```
+    pub fn encode_bytes(&self, bytes: &[u8]) -> String {
+        self.encode_bytes_with_order(bytes, ByteOrder::BigEndian)
+    }
+
+    /// Encodes a byte slice, interpreting it as a number in the given byte order.
+    ///
+    /// With [`ByteOrder::LittleEndian`] the zero bytes at the *end* of the
+    /// slice are the most significant ones and become leading zero-digit characters.
+    pub fn encode_bytes_with_order(&self, bytes: &[u8], order: ByteOrder) -> String {
+        match order {
+            ByteOrder::BigEndian => self.encode_be_bytes(bytes),
+            ByteOrder::LittleEndian => {
+                let reversed: Vec<u8> = bytes.iter().rev().copied().collect();
+                self.encode_be_bytes(&reversed)
+            }
+        }
+    }
+
+    /// Decodes a string produced by [`Alphabet::encode_bytes`] into big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`](crate::Error::InvalidCharacter) if
+    /// `input` contains a character that is not in the table.
+    pub fn decode_bytes(&self, input: &str) -> Result<Vec<u8>> {
+        self.decode_bytes_with_order(input, ByteOrder::BigEndian)
+    }
+
+    /// Decodes a string produced by [`Alphabet::encode_bytes_with_order`] into
+    /// bytes in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`](crate::Error::InvalidCharacter) if
+    /// `input` contains a character that is not in the table.
+    pub fn decode_bytes_with_order(&self, input: &str, order: ByteOrder) -> Result<Vec<u8>> {
+        let mut bytes = self.decode_be_bytes(input)?;
+        if order == ByteOrder::LittleEndian {
+            bytes.reverse();
+        }
+        Ok(bytes)
+    }
+
+    /// Encode bytes given most significant first.
+    fn encode_be_bytes(&self, bytes: &[u8]) -> String {
+        let zero_count = bytes.iter().take_while(|&&b| b == 0).count();
+        let mut out: String = std::iter::repeat_n(self.zero_char(), zero_count).collect();
+        if zero_count < bytes.len() {
+            out.push_str(&self.format_unsigned(BigInt::from_bytes_be(&bytes[zero_count..])));
+        }
+        out
+    }
+
+    /// Decode into bytes ordered most significant first.
+    fn decode_be_bytes(&self, input: &str) -> Result<Vec<u8>> {
+        let zero = self.zero_char();
+        let zero_count = input.chars().take_while(|&ch| ch == zero).count();
+
+        // Leading zero digits do not change the value, so parse the whole
+        // input to keep error positions relative to it
+        let value = self.parse_unsigned(input)?;
+        let mut bytes = vec![0; zero_count];
+        bytes.extend(value.to_bytes_be());
+        Ok(bytes)
+    }
+}
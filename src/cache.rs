@@ -0,0 +1,178 @@
+/*!
+Converter cache used by [`convert_base`](crate::convert_base)
+
+Building a [`Converter`] validates both tables and builds their lookup tables,
+which costs more than converting a short number. `convert_base` therefore
+keeps recently used converters in a small, bounded, thread-safe cache keyed
+on the table pair, so repeated calls cost about the same as reusing a
+`Converter`.
+
+Pairs of the [`base`] presets are always served from
+precomputed alphabets and never occupy the cache. Call [`set_capacity`]
+with `0` to opt out of caching other tables.
+
+# Example
+
+```
+use anybase::{cache, convert_base};
+
+let base62 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+assert_eq!(convert_base("1000000", "0123456789", base62).unwrap(), "4C92");
+
+cache::set_capacity(0);
+assert_eq!(cache::len(), 0);
+assert_eq!(convert_base("1000000", "0123456789", base62).unwrap(), "4C92");
+cache::set_capacity(cache::DEFAULT_CAPACITY);
+```
+*/
+
+use crate::alphabet::Alphabet;
+use crate::base;
+use crate::converter::Converter;
+use crate::error::Result;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Number of table pairs the cache holds unless changed with [`set_capacity`]
+pub const DEFAULT_CAPACITY: usize = 16;
+
+struct Cache {
+    capacity: usize,
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    converter: Converter,
+    /// [`CLOCK`] value of the last use, so hits only need the read lock
+    last_used: AtomicU64,
+}
+
+impl Cache {
+    fn find(&self, src_table: &str, dst_table: &str) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|e| e.converter.src_table() == src_table && e.converter.dst_table() == dst_table)
+    }
+
+    /// Evicts least recently used entries until at most `len` remain
+    fn truncate(&mut self, len: usize) {
+        while self.entries.len() > len {
+            let oldest = self
+                .entries
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, e)| e.last_used.load(Ordering::Relaxed))
+                .map(|(i, _)| i)
+                .unwrap();
+            self.entries.swap_remove(oldest);
+        }
+    }
+}
+
+static CACHE: RwLock<Cache> = RwLock::new(Cache {
+    capacity: DEFAULT_CAPACITY,
+    entries: Vec::new(),
+});
+
+/// Source of the recency stamps
+static CLOCK: AtomicU64 = AtomicU64::new(0);
+
+fn tick() -> u64 {
+    CLOCK.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Lock the cache for reading; a panic while it was held cannot leave it
+/// inconsistent
+fn read() -> RwLockReadGuard<'static, Cache> {
+    CACHE.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Lock the cache for writing; see [`read`]
+fn write() -> RwLockWriteGuard<'static, Cache> {
+    CACHE.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sets the maximum number of table pairs kept by the cache.
+///
+/// Least recently used pairs are evicted when the cache is full. A capacity
+/// of `0` disables caching: every call builds a new converter.
+pub fn set_capacity(capacity: usize) {
+    let mut cache = write();
+    cache.capacity = capacity;
+    cache.truncate(capacity);
+}
+
+/// Returns the maximum number of table pairs kept by the cache.
+pub fn capacity() -> usize {
+    read().capacity
+}
+
+/// Returns the number of table pairs currently cached.
+pub fn len() -> usize {
+    read().entries.len()
+}
+
+/// Returns `true` if the cache holds a converter for the table pair.
+///
+/// Does not count as a use of the pair.
+pub fn contains(src_table: &str, dst_table: &str) -> bool {
+    read().find(src_table, dst_table).is_some()
+}
+
+/// Removes all cached converters, keeping the capacity.
+pub fn clear() {
+    write().entries.clear();
+}
+
+/// Returns a converter for the table pair, from the presets or the cache
+/// when possible
+///
+/// Hits only take the read lock, so threads converting with cached pairs do
+/// not wait for each other.
+///
+/// # Errors
+///
+/// Returns the same errors as [`Converter::try_new`]. Invalid tables are
+/// never cached.
+pub(crate) fn converter(src_table: &str, dst_table: &str) -> Result<Converter> {
+    if let (Some(src), Some(dst)) = (preset(src_table), preset(dst_table)) {
+        return Ok(Converter::from_alphabets(src, dst));
+    }
+
+    {
+        let cache = read();
+        if cache.capacity == 0 {
+            drop(cache);
+            return Converter::try_new(src_table, dst_table);
+        }
+        if let Some(entry) = cache.find(src_table, dst_table) {
+            entry.last_used.store(tick(), Ordering::Relaxed);
+            return Ok(entry.converter.clone());
+        }
+    }
+
+    // Build outside the lock so other threads are not blocked meanwhile
+    let converter = Converter::try_new(src_table, dst_table)?;
+    let mut cache = write();
+    if cache.find(src_table, dst_table).is_none() && cache.capacity > 0 {
+        let capacity = cache.capacity;
+        cache.truncate(capacity - 1);
+        cache.entries.push(Entry {
+            converter: converter.clone(),
+            last_used: AtomicU64::new(tick()),
+        });
+    }
+    Ok(converter)
+}
+
+/// Returns the shared alphabet of a [`base`] preset table
+fn preset(table: &str) -> Option<Arc<Alphabet>> {
+    const TABLES: [&str; 4] = [base::BIN, base::OCT, base::DEC, base::HEX];
+    static PRESETS: OnceLock<[Arc<Alphabet>; 4]> = OnceLock::new();
+
+    let index = TABLES.iter().position(|&t| t == table)?;
+    let presets = PRESETS.get_or_init(|| {
+        TABLES.map(|t| Arc::new(Alphabet::new(t).expect("preset tables are valid")))
+    });
+    Some(Arc::clone(&presets[index]))
+}
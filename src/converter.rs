@@ -1,11 +1,73 @@
+use crate::alphabet::Alphabet;
 use crate::big_int::BigInt;
-use std::collections::HashMap;
+use crate::error::{Error, Result, Table};
+use crate::native::NativeInt;
+use crate::radix;
+use crate::strategy::{self, Strategy};
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::Arc;
+
+/// Scratch buffers larger than this many digits or limbs are released after
+/// use instead of being kept for the next conversion on the thread
+const SCRATCH_RETAIN_LIMIT: usize = 1 << 16;
+
+/// Per-thread buffers reused across conversions
+struct Scratch {
+    /// Source digits
+    digits: Vec<u32>,
+    /// Destination digits
+    out: Vec<u32>,
+    big: BigInt,
+}
+
+impl Scratch {
+    fn new() -> Self {
+        Scratch {
+            digits: Vec::new(),
+            out: Vec::new(),
+            big: BigInt::zero(),
+        }
+    }
+
+    fn is_oversized(&self) -> bool {
+        self.digits.capacity() > SCRATCH_RETAIN_LIMIT
+            || self.out.capacity() > SCRATCH_RETAIN_LIMIT
+            || self.big.limb_capacity() > SCRATCH_RETAIN_LIMIT
+    }
+}
+
+thread_local! {
+    static SCRATCH: RefCell<Scratch> = RefCell::new(Scratch::new());
+}
+
+/// Run `f` with the thread's scratch buffers
+fn with_scratch<R>(f: impl FnOnce(&mut Scratch) -> R) -> R {
+    SCRATCH.with(|cell| match cell.try_borrow_mut() {
+        Ok(mut scratch) => {
+            let result = f(&mut scratch);
+            if scratch.is_oversized() {
+                *scratch = Scratch::new();
+            }
+            result
+        }
+        // Re-entered, e.g. from the writer passed to `Converter::write_to`
+        Err(_) => f(&mut Scratch::new()),
+    })
+}
 
 /// A converter for transforming numbers between arbitrary bases using custom character tables.
 /// 
 /// The `Converter` allows conversion of string representations of numbers from one base to another,
 /// where each base is defined by a custom character table. Each character in the table represents
 /// a digit in that base.
+///
+/// A `Converter` is a pairing of two validated [`Alphabet`]s. The alphabets are
+/// shared through [`Arc`], so [`Converter::inverse`] and building several converters
+/// from the same alphabets are cheap.
+///
+/// A `Converter` owns its tables, so it is `'static`, `Send` and `Sync`, and
+/// cloning it only bumps two reference counts.
 /// 
 /// # Examples
 /// 
@@ -16,25 +78,27 @@ use std::collections::HashMap;
 /// let result = converter.convert("1010").unwrap();
 /// assert_eq!(result, "10");
 /// ```
-pub struct Converter<'a> {
-    src_table: &'a str,
-    dst_table: &'a str,
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Converter {
+    src: Arc<Alphabet>,
+    dst: Arc<Alphabet>,
 
-    src_map: HashMap<char, u32>,
-    dst_chars: Vec<char>,
+    /// Conversion algorithm, derived from the two bases
+    strategy: Strategy,
 }
 
-impl<'a> Converter<'a> {
+impl Converter {
     /// Creates a new `Converter` with specified source and destination character tables.
     /// 
     /// # Arguments
     /// 
-    /// * `src_table` - A string slice representing the source base character table
-    /// * `dst_table` - A string slice representing the destination base character table
+    /// * `src_table` - The source base character table, as a `&str` or an owned `String`
+    /// * `dst_table` - The destination base character table, as a `&str` or an owned `String`
     /// 
     /// # Panics
     /// 
-    /// Panics if either table is empty or contains duplicate characters.
+    /// Panics if either table is invalid. Use [`Converter::try_new`] to handle
+    /// invalid tables without panicking.
     /// 
     /// # Examples
     /// 
@@ -42,36 +106,69 @@ impl<'a> Converter<'a> {
     /// use anybase::Converter;
     /// let converter = Converter::new("01", "0123456789");
     /// ```                 
-    pub fn new(src_table: &'a str, dst_table: &'a str) -> Self {
+    pub fn new(src_table: impl Into<String>, dst_table: impl Into<String>) -> Self {
+        match Converter::try_new(src_table, dst_table) {
+            Ok(converter) => converter,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Creates a new `Converter`, returning an error if either table is invalid.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `src_table` - The source base character table, as a `&str` or an owned `String`
+    /// * `dst_table` - The destination base character table, as a `&str` or an owned `String`
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if either table:
+    /// - is empty ([`Error::EmptyTable`])
+    /// - contains a single character ([`Error::SingleCharacterTable`])
+    /// - contains duplicate characters ([`Error::DuplicateCharacter`])
+    /// - is too large to index with a `u32` digit ([`Error::SizeLimitExceeded`])
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use anybase::{Converter, Error, Table};
+    /// 
+    /// assert!(Converter::try_new("01", "0123456789").is_ok());
+    /// assert_eq!(
+    ///     Converter::try_new("01", "0110").err(),
+    ///     Some(Error::DuplicateCharacter { table: Table::Destination, ch: '1', first: 1, second: 2 })
+    /// );
+    /// ```
+    pub fn try_new(src_table: impl Into<String>, dst_table: impl Into<String>) -> Result<Self> {
+        let src = Alphabet::with_kind(src_table.into(), Table::Source)?;
+        let dst = Alphabet::with_kind(dst_table.into(), Table::Destination)?;
+        Ok(Converter::from_alphabets(src, dst))
+    }
+
+    /// Creates a new `Converter` from two already validated alphabets.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `src` - The source alphabet, either owned or shared through an [`Arc`]
+    /// * `dst` - The destination alphabet, either owned or shared through an [`Arc`]
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use anybase::{Alphabet, Converter};
+    /// use std::sync::Arc;
+    /// 
+    /// let dec = Arc::new(Alphabet::new("0123456789").unwrap());
+    /// let hex = Arc::new(Alphabet::new("0123456789abcdef").unwrap());
+    /// let converter = Converter::from_alphabets(dec.clone(), hex.clone());
+    /// assert_eq!(converter.convert("255").unwrap(), "ff");
+    /// ```
+    pub fn from_alphabets(src: impl Into<Arc<Alphabet>>, dst: impl Into<Arc<Alphabet>>) -> Self {
+        let (src, dst) = (src.into(), dst.into());
         Converter {
-            src_table,
-            dst_table,
-            src_map: {
-                if src_table.is_empty() {
-                    panic!("src_table is empty");
-                }
-                let mut map = HashMap::new();
-                for (i, ch) in src_table.chars().enumerate() {
-                    if map.insert(ch, i as u32).is_some() {
-                        panic!("src_table contains duplicate characters");
-                    }
-                }
-                map
-            },
-            dst_chars: {
-                if dst_table.is_empty() {
-                    panic!("dst_table is empty");
-                }
-                let chars: Vec<char> = dst_table.chars().collect();
-                let unique_count = chars
-                    .iter()
-                    .collect::<std::collections::HashSet<_>>()
-                    .len();
-                if unique_count != chars.len() {
-                    panic!("dst_table contains duplicate characters");
-                }
-                chars
-            },
+            strategy: Strategy::select(src.base(), dst.base()),
+            src,
+            dst,
         }
     }
 
@@ -80,6 +177,7 @@ impl<'a> Converter<'a> {
     /// # Returns
     /// 
     /// A new `Converter` instance with source and destination tables swapped.
+    /// The alphabets are shared with `self`, so no validation is repeated.
     /// 
     /// # Examples
     /// 
@@ -91,11 +189,100 @@ impl<'a> Converter<'a> {
     /// assert_eq!(converter.dst_table(), inverse_converter.src_table());
     /// ```
     pub fn inverse(&self) -> Self {
-        Converter::new(self.dst_table, self.src_table)
+        Converter::from_alphabets(Arc::clone(&self.dst), Arc::clone(&self.src))
+    }
+
+    /// Enables signed values in both tables; see [`Alphabet::with_signs`].
+    ///
+    /// The sign of the input is carried over to the output, where negative
+    /// values are prefixed with `negative`. Zero is never signed, so `-0`
+    /// converts to the zero character.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SignConflict`] if a sign character is in either
+    /// table or is another configured symbol, or if both signs are the same
+    /// character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter, Error, Table};
+    ///
+    /// let converter = Converter::new(base::DEC, base::HEX).with_signs('-', Some('+')).unwrap();
+    /// assert_eq!(converter.convert("-255").unwrap(), "-ff");
+    /// assert_eq!(converter.convert("+255").unwrap(), "ff");
+    /// assert_eq!(converter.convert("-0").unwrap(), "0");
+    /// assert_eq!(
+    ///     Converter::new(base::DEC, "0123456789+-").with_signs('-', None),
+    ///     Err(Error::SignConflict { table: Table::Destination, ch: '-' })
+    /// );
+    /// ```
+    pub fn with_signs(self, negative: char, positive: Option<char>) -> Result<Self> {
+        let src =
+            Arc::unwrap_or_clone(self.src).with_signs_for(negative, positive, Table::Source)?;
+        let dst = Arc::unwrap_or_clone(self.dst).with_signs_for(
+            negative,
+            positive,
+            Table::Destination,
+        )?;
+        Ok(Converter::from_alphabets(src, dst))
+    }
+
+    /// Enables fractional values in both tables; see [`Alphabet::with_radix_point`]
+    /// and [`Converter::convert_fraction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RadixPointConflict`] if `point` is in either table or
+    /// is another configured symbol.
+    pub fn with_radix_point(self, point: char) -> Result<Self> {
+        let src = Arc::unwrap_or_clone(self.src).with_radix_point_for(point, Table::Source)?;
+        let dst = Arc::unwrap_or_clone(self.dst).with_radix_point_for(point, Table::Destination)?;
+        Ok(Converter::from_alphabets(src, dst))
+    }
+
+    /// Enables repeating fractions in both tables; see
+    /// [`Alphabet::with_repetend_marks`] and [`Converter::convert_rational`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RepetendConflict`] if a mark is in either table or is
+    /// another configured symbol, or if both marks are the same character.
+    pub fn with_repetend_marks(self, open: char, close: char) -> Result<Self> {
+        let src =
+            Arc::unwrap_or_clone(self.src).with_repetend_marks_for(open, close, Table::Source)?;
+        let dst = Arc::unwrap_or_clone(self.dst).with_repetend_marks_for(
+            open,
+            close,
+            Table::Destination,
+        )?;
+        Ok(Converter::from_alphabets(src, dst))
+    }
+
+    /// Enables scientific notation in both tables; see
+    /// [`Alphabet::with_exponent_marker`] and [`Converter::format_float`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ExponentConflict`] if `marker` is in either table or
+    /// is another configured symbol.
+    pub fn with_exponent_marker(self, marker: char) -> Result<Self> {
+        let src = Arc::unwrap_or_clone(self.src).with_exponent_marker_for(marker, Table::Source)?;
+        let dst =
+            Arc::unwrap_or_clone(self.dst).with_exponent_marker_for(marker, Table::Destination)?;
+        Ok(Converter::from_alphabets(src, dst))
     }
 
     /// Converts an input string from source base to destination base.
     /// 
+    /// When both bases are equal, each character is substituted directly.
+    /// When both bases are powers of two (e.g. hex to binary or base32 to
+    /// base64), or one base is an exact power of the other (e.g. base 10 to
+    /// base 1000, or base 36 to base 6), the conversion regroups digits in
+    /// linear time instead of going through a big integer. The output is the
+    /// same either way.
+    /// 
     /// # Arguments
     /// 
     /// * `input` - The string to convert, using characters from the source table
@@ -103,7 +290,13 @@ impl<'a> Converter<'a> {
     /// # Returns
     /// 
     /// `Ok(String)` containing the converted value in destination base characters,
-    /// or `Err(String)` with an error message if conversion fails.
+    /// or an [`Error`] if conversion fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the source table, or [`Error::NegativeValue`] if
+    /// `input` is negative and the destination table has no negative sign.
     /// 
     /// # Examples
     /// 
@@ -113,9 +306,338 @@ impl<'a> Converter<'a> {
     /// let result = converter.convert("1010").unwrap();
     /// assert_eq!(result, "10");
     /// ```
-    pub fn convert(&self, input: &str) -> Result<String, String> {
-        let b = self.parse_to_bigint(input)?;
-        self.bigint_to_dst_table(b)
+    pub fn convert(&self, input: &str) -> Result<String> {
+        let mut out = String::new();
+        self.convert_into(input, &mut out)?;
+        Ok(out)
+    }
+
+    /// Converts `input` and appends the result to `out`.
+    ///
+    /// Produces the same characters as [`Converter::convert`], but reuses the
+    /// capacity of `out`. Intermediate digits and big integers are kept in
+    /// per-thread scratch buffers, so once `out` and the scratch buffers have
+    /// grown, converting inputs of similar size does not allocate. Inputs
+    /// large enough for the divide-and-conquer algorithms still allocate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the source table. `out` is left unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter};
+    /// let converter = Converter::new(base::DEC, base::HEX);
+    /// let mut out = String::with_capacity(16);
+    /// for input in ["255", "4096"] {
+    ///     out.clear();
+    ///     converter.convert_into(input, &mut out).unwrap();
+    /// }
+    /// assert_eq!(out, "1000");
+    /// ```
+    pub fn convert_into(&self, input: &str, out: &mut String) -> Result<()> {
+        self.with_dst_digits(input, |sign, digits| {
+            out.extend(sign);
+            self.dst.render_into(digits, out);
+        })
+    }
+
+    /// Converts `input` and writes the result to `w`.
+    ///
+    /// Like [`Converter::convert_into`], this does not allocate in the
+    /// steady state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the source table, or [`Error::WriteFailed`] if `w`
+    /// returns an error. Nothing is written when `input` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter};
+    /// use std::fmt::Write;
+    ///
+    /// let converter = Converter::new(base::DEC, base::HEX);
+    /// let mut out = String::from("0x");
+    /// converter.write_to("255", &mut out).unwrap();
+    /// assert_eq!(out, "0xff");
+    /// ```
+    pub fn write_to(&self, input: &str, w: &mut impl fmt::Write) -> Result<()> {
+        self.with_dst_digits(input, |sign, digits| {
+            sign.map_or(Ok(()), |ch| w.write_char(ch))?;
+            self.dst.write_digits(digits, w)
+        })?
+        .map_err(|_| Error::WriteFailed)
+    }
+
+    /// Converts `input` and writes the result as UTF-8 into the start of `buf`.
+    ///
+    /// Never allocates for inputs below the divide-and-conquer threshold once
+    /// the per-thread scratch buffers have grown.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the source table, or [`Error::BufferTooSmall`] if the
+    /// result does not fit in `buf`. Nothing is written on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter, Error};
+    /// let converter = Converter::new(base::DEC, base::HEX);
+    /// let mut buf = [0u8; 4];
+    /// let len = converter.convert_to_slice("65535", &mut buf).unwrap();
+    /// assert_eq!(&buf[..len], b"ffff");
+    /// assert_eq!(
+    ///     converter.convert_to_slice("65536", &mut buf),
+    ///     Err(Error::BufferTooSmall { needed: 5, available: 4 })
+    /// );
+    /// ```
+    pub fn convert_to_slice(&self, input: &str, buf: &mut [u8]) -> Result<usize> {
+        self.with_dst_digits(input, |sign, digits| {
+            let sign_len = sign.map_or(0, char::len_utf8);
+            let needed = sign_len + self.dst.rendered_len(digits);
+            if needed > buf.len() {
+                return Err(Error::BufferTooSmall {
+                    needed,
+                    available: buf.len(),
+                });
+            }
+            if let Some(ch) = sign {
+                ch.encode_utf8(buf);
+            }
+            Ok(sign_len + self.dst.render_into_slice(digits, &mut buf[sign_len..]))
+        })?
+    }
+
+    /// Decodes `input`, converts it to destination digits and calls `f` with
+    /// the destination sign character, if any, and the digits
+    ///
+    /// The digits are most significant first, without leading zeros, and live
+    /// either on the stack or in the thread's scratch buffers.
+    fn with_dst_digits<R>(
+        &self,
+        input: &str,
+        f: impl FnOnce(Option<char>, &[u32]) -> R,
+    ) -> Result<R> {
+        let (negative, sign, input) = self.src.split_sign_strict(input)?;
+        if self.strategy == Strategy::General {
+            // Values that fit in a u128 are converted in registers; overflow
+            // is detected while parsing and falls back to BigInt
+            match self.src.decode_unsigned::<u128>(input) {
+                Ok(value) => {
+                    let mut buf = [0; 128];
+                    return self.with_dst_sign(
+                        negative,
+                        self.dst.native_digits(value, &mut buf),
+                        f,
+                    );
+                }
+                Err(Error::Overflow { .. }) => {}
+                Err(e) => return Err(e.after_prefix(sign)),
+            }
+        }
+
+        with_scratch(|scratch| {
+            let Scratch { digits, out, big } = scratch;
+            self.src
+                .decode_digits_into(input, digits)
+                .map_err(|e| e.after_prefix(sign))?;
+            let src_base = self.src.base() as u32;
+            let dst_base = self.dst.base() as u32;
+            let out: &[u32] = match self.strategy {
+                Strategy::General => {
+                    radix::from_digits_into(digits, src_base, big);
+                    radix::to_digits_into(big, dst_base, out);
+                    out
+                }
+                Strategy::Transliterate => strategy::strip_leading(digits),
+                Strategy::BitRegroup { src_bits, dst_bits } => {
+                    strategy::regroup_bits(digits, src_bits, dst_bits, out);
+                    out
+                }
+                Strategy::Group { digits: group } => {
+                    strategy::group_digits(digits, src_base, group, out);
+                    out
+                }
+                Strategy::Split { digits: group } => {
+                    strategy::split_digits(digits, dst_base, group, out);
+                    out
+                }
+            };
+            self.with_dst_sign(negative, out, f)
+        })
+    }
+
+    /// Calls `f` with the destination sign of a value and its digits
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeValue`] if the value is negative and the
+    /// destination table has no negative sign.
+    fn with_dst_sign<R>(
+        &self,
+        negative: bool,
+        digits: &[u32],
+        f: impl FnOnce(Option<char>, &[u32]) -> R,
+    ) -> Result<R> {
+        // Zero is never signed
+        let sign = self.dst.sign_char(negative && digits != [0])?;
+        Ok(f(sign, digits))
+    }
+
+    /// Substitutes each character of `input` with the destination character
+    /// of the same digit, preserving the input length exactly.
+    /// 
+    /// Unlike [`Converter::convert`], leading zeros are kept. This is only
+    /// defined when both tables have the same base. A negative sign is
+    /// carried over unless the value is zero.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::BaseMismatch`] if the bases differ,
+    /// [`Error::InvalidCharacter`] if `input` contains a character that is
+    /// not in the source table, or [`Error::NegativeValue`] if `input` is
+    /// negative and the destination table has no negative sign.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use anybase::Converter;
+    /// let converter = Converter::new("0123456789abcdef", "0123456789ABCDEF");
+    /// assert_eq!(converter.convert("00ff").unwrap(), "FF");
+    /// assert_eq!(converter.transliterate("00ff").unwrap(), "00FF");
+    /// ```
+    pub fn transliterate(&self, input: &str) -> Result<String> {
+        if self.strategy != Strategy::Transliterate {
+            return Err(Error::BaseMismatch {
+                src_base: self.src_base(),
+                dst_base: self.dst_base(),
+            });
+        }
+        let (negative, sign, input) = self.src.split_sign_strict(input)?;
+        let digits = self
+            .src
+            .decode_digits(input)
+            .map_err(|e| e.after_prefix(sign))?;
+        let mut out = String::new();
+        out.extend(self.dst.sign_char(negative && digits.iter().any(|&d| d != 0))?);
+        self.dst.render_into(&digits, &mut out);
+        Ok(out)
+    }
+
+    /// Encodes a primitive integer using the destination table.
+    /// 
+    /// Uses native arithmetic only; see [`Alphabet::encode`].
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::NegativeValue`](crate::Error::NegativeValue) if `value` is negative
+    /// and the destination table has no negative sign.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use anybase::{base, Converter};
+    /// let converter = Converter::new(base::DEC, base::HEX);
+    /// assert_eq!(converter.encode(u64::MAX).unwrap(), "ffffffffffffffff");
+    /// ```
+    pub fn encode<T: NativeInt>(&self, value: T) -> Result<String> {
+        self.dst.encode(value)
+    }
+
+    /// Decodes a string in the source table into a primitive integer.
+    /// 
+    /// Uses native arithmetic only; see [`Alphabet::decode`].
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidCharacter`](crate::Error::InvalidCharacter) if `input`
+    /// contains a character that is not in the source table, or
+    /// [`Error::Overflow`](crate::Error::Overflow) if the value does not fit in `T`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use anybase::{base, Converter};
+    /// let converter = Converter::new(base::DEC, base::HEX);
+    /// assert_eq!(converter.decode::<u16>("65535").unwrap(), u16::MAX);
+    /// assert!(converter.decode::<u16>("65536").is_err());
+    /// ```
+    pub fn decode<T: NativeInt>(&self, input: &str) -> Result<T> {
+        self.src.decode(input)
+    }
+
+    /// Parses a string in the source table into a [`BigInt`].
+    /// 
+    /// Together with [`Converter::format`] this allows arithmetic between
+    /// parsing and formatting; see [`Alphabet::parse`].
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidCharacter`](crate::Error::InvalidCharacter) if `input`
+    /// contains a character that is not in the source table.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use anybase::{base, BigInt, Converter};
+    /// let converter = Converter::new(base::DEC, base::HEX);
+    /// let value = converter.parse("4095").unwrap() * BigInt::from(16u32);
+    /// assert_eq!(converter.format(&value).unwrap(), "fff0");
+    /// ```
+    pub fn parse(&self, input: &str) -> Result<BigInt> {
+        self.src.parse(input)
+    }
+
+    /// Formats a [`BigInt`] using the destination table.
+    /// 
+    /// See [`Alphabet::format`].
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::NegativeValue`](crate::Error::NegativeValue) if
+    /// `value` is negative and the destination table has no negative sign.
+    pub fn format(&self, value: &BigInt) -> Result<String> {
+        self.dst.format(value)
+    }
+
+    /// Encodes a big-endian byte slice using the destination table.
+    /// 
+    /// Leading zero bytes are preserved; see [`Alphabet::encode_bytes`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use anybase::{base, Converter};
+    /// let converter = Converter::new(base::HEX, base::HEX);
+    /// let encoded = converter.encode_bytes(&[0x00, 0xbe, 0xef]);
+    /// assert_eq!(encoded, "0beef");
+    /// assert_eq!(converter.decode_bytes(&encoded).unwrap(), [0x00, 0xbe, 0xef]);
+    /// ```
+    pub fn encode_bytes(&self, bytes: &[u8]) -> String {
+        self.dst.encode_bytes(bytes)
+    }
+
+    /// Decodes a string in the source table into big-endian bytes.
+    /// 
+    /// Leading zero-digit characters become leading zero bytes; see [`Alphabet::decode_bytes`].
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::InvalidCharacter`](crate::Error::InvalidCharacter) if `input`
+    /// contains a character that is not in the source table.
+    pub fn decode_bytes(&self, input: &str) -> Result<Vec<u8>> {
+        self.src.decode_bytes(input)
     }
 
     /// Returns the source character table.
@@ -124,7 +646,7 @@ impl<'a> Converter<'a> {
     /// 
     /// A string slice representing the source base character table.
     pub fn src_table(&self) -> &str {
-        self.src_table
+        self.src.as_str()
     }
 
     /// Returns the destination character table.
@@ -133,7 +655,17 @@ impl<'a> Converter<'a> {
     /// 
     /// A string slice representing the destination base character table.
     pub fn dst_table(&self) -> &str {
-        self.dst_table
+        self.dst.as_str()
+    }
+
+    /// Returns the source alphabet.
+    pub fn src_alphabet(&self) -> &Arc<Alphabet> {
+        &self.src
+    }
+
+    /// Returns the destination alphabet.
+    pub fn dst_alphabet(&self) -> &Arc<Alphabet> {
+        &self.dst
     }
 
     /// Returns the size of the source base.
@@ -142,7 +674,7 @@ impl<'a> Converter<'a> {
     /// 
     /// The number of characters in the source character table, which represents the source base.
     pub fn src_base(&self) -> usize {
-        self.src_table.chars().count()
+        self.src.base()
     }
 
     /// Returns the size of the destination base.
@@ -151,78 +683,15 @@ impl<'a> Converter<'a> {
     /// 
     /// The number of characters in the destination character table, which represents the destination base.
     pub fn dst_base(&self) -> usize {
-        self.dst_table.chars().count()
+        self.dst.base()
     }
+}
 
-    /// Parse input string (using src_table) into BigInt
-    ///
-    /// Converts a string representation in the source base to a BigInt.
-    ///
-    /// # Arguments
-    ///
-    /// * `input` - The string to parse
-    /// * `src_table` - Character table defining the source base
-    ///
-    /// # Returns
-    ///
-    /// Result containing the parsed BigInt or an error message
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - src_table is empty
-    /// - src_table contains duplicate characters
-    /// - input contains characters not in src_table
-    fn parse_to_bigint(&self, input: &str) -> Result<BigInt, String> {
-        let mut big = BigInt::zero();
-        let src_base = self.src_table.chars().count() as u32;
-        for ch in input.chars() {
-            let digit = match self.src_map.get(&ch) {
-                Some(&d) => d,
-                None => return Err(format!("Input character '{}' not found in src_table", ch)),
-            };
-            // big = big * src_base + digit
-            big.mul_small(src_base);
-            big.add_small(digit);
-        }
-        Ok(big)
-    }
-
-    /// Convert BigInt to target character table representation (integers only)
-    ///
-    /// Converts a BigInt to its string representation in the destination base.
-    ///
-    /// # Arguments
-    ///
-    /// * `big` - The BigInt to convert
-    /// * `dst_table` - Character table defining the destination base
-    ///
-    /// # Returns
-    ///
-    /// Result containing the converted string or an error message
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - dst_table is empty
-    /// - dst_table contains duplicate characters
-    fn bigint_to_dst_table(&self, mut big: BigInt) -> Result<String, String> {
-        if self.dst_table.is_empty() {
-            return Err("dst_table is empty".to_string());
-        }
-
-        let dst_base = self.dst_chars.len() as u32;
-
-        if big.is_zero() {
-            return Ok(self.dst_chars[0].to_string());
-        }
-
-        let mut out_chars: Vec<char> = Vec::new();
-        while !big.is_zero() {
-            let rem = big.div_mod_small(dst_base);
-            out_chars.push(self.dst_chars[rem as usize]);
-        }
-        out_chars.reverse();
-        Ok(out_chars.into_iter().collect())
+impl fmt::Debug for Converter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Converter")
+            .field("src", &self.src)
+            .field("dst", &self.dst)
+            .finish()
     }
 }
@@ -0,0 +1,311 @@
+use std::fmt;
+
+/// Result type used throughout the crate
+///
+/// Shorthand for `std::result::Result<T, anybase::Error>`.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Identifies which character table an [`Error`] refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Table {
+    /// The source character table (`src_table`)
+    Source,
+    /// The destination character table (`dst_table`)
+    Destination,
+    /// The table of a standalone [`Alphabet`](crate::Alphabet)
+    Alphabet,
+}
+
+impl Table {
+    /// Returns the name of the table as used in error messages.
+    fn name(self) -> &'static str {
+        match self {
+            Table::Source => "src_table",
+            Table::Destination => "dst_table",
+            Table::Alphabet => "alphabet",
+        }
+    }
+}
+
+/// Errors returned by the conversion functions of this crate.
+///
+/// Every variant carries enough positional information to point at the
+/// offending character, so callers can report it without parsing messages.
+///
+/// # Examples
+///
+/// ```
+/// use anybase::{convert_base, Error};
+///
+/// let err = convert_base("1x0", "01", "0123456789").unwrap_err();
+/// assert_eq!(
+///     err,
+///     Error::InvalidCharacter { ch: 'x', byte_offset: 1, char_index: 1 }
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input contains a character that is not part of the source table.
+    InvalidCharacter {
+        /// The offending character
+        ch: char,
+        /// Byte offset of the character within the input string
+        byte_offset: usize,
+        /// Index of the character counted in `char`s
+        char_index: usize,
+    },
+    /// A digit value is not less than the base it is used with.
+    InvalidDigit {
+        /// The offending digit value
+        digit: u32,
+        /// Index of the digit within the digit sequence
+        index: usize,
+        /// The base the digit was checked against
+        base: usize,
+    },
+    /// A numeric base is less than 2.
+    InvalidBase {
+        /// The offending base
+        base: usize,
+    },
+    /// A decoded value does not fit in the requested integer type.
+    Overflow {
+        /// Name of the requested type, e.g. `"u64"`
+        ty: &'static str,
+    },
+    /// A negative value was given where only non-negative values are supported.
+    NegativeValue,
+    /// An operation that requires equal source and destination bases was
+    /// used with different ones.
+    BaseMismatch {
+        /// The source base
+        src_base: usize,
+        /// The destination base
+        dst_base: usize,
+    },
+    /// A character table contains no characters.
+    EmptyTable {
+        /// The table that is empty
+        table: Table,
+    },
+    /// A character table contains only one character, so it cannot
+    /// represent any non-zero value.
+    SingleCharacterTable {
+        /// The table with a single character
+        table: Table,
+    },
+    /// A character table contains the same character more than once.
+    DuplicateCharacter {
+        /// The table containing the duplicate
+        table: Table,
+        /// The duplicated character
+        ch: char,
+        /// Char index of the first occurrence
+        first: usize,
+        /// Char index of the second occurrence
+        second: usize,
+    },
+    /// A character table has more characters than the supported maximum.
+    SizeLimitExceeded {
+        /// The table that is too large
+        table: Table,
+        /// Maximum number of characters allowed
+        limit: usize,
+        /// Actual number of characters
+        actual: usize,
+    },
+    /// A caller-provided output buffer cannot hold the result.
+    BufferTooSmall {
+        /// Number of bytes the result needs
+        needed: usize,
+        /// Size of the buffer in bytes
+        available: usize,
+    },
+    /// A [`fmt::Write`] sink returned an error.
+    WriteFailed,
+    /// A sign character is also in the character table, or the negative and
+    /// positive signs are the same character.
+    SignConflict {
+        /// The table the sign was configured for
+        table: Table,
+        /// The conflicting sign character
+        ch: char,
+    },
+    /// A radix point character is also in the character table or is a sign
+    /// character.
+    RadixPointConflict {
+        /// The table the radix point was configured for
+        table: Table,
+        /// The conflicting radix point character
+        ch: char,
+    },
+    /// A repetend mark is also in the character table or is another
+    /// configured symbol, or both marks are the same character.
+    RepetendConflict {
+        /// The table the marks were configured for
+        table: Table,
+        /// The conflicting mark
+        ch: char,
+    },
+    /// An exponent marker is also in the character table or is another
+    /// configured symbol.
+    ExponentConflict {
+        /// The table the marker was configured for
+        table: Table,
+        /// The conflicting marker
+        ch: char,
+    },
+    /// A value with a fraction part was given where only integers are
+    /// supported, e.g. a table without a radix point.
+    FractionalValue,
+    /// A repeating fraction was given where only terminating fractions are
+    /// supported, e.g. a table without repetend marks.
+    RepeatingValue,
+    /// A fraction has a zero denominator.
+    ZeroDenominator,
+    /// A NaN or infinity was given where it cannot be told apart from a
+    /// number, e.g. a table containing the characters of `NaN`.
+    NonFiniteValue,
+    /// A value does not fit in the requested number of digits.
+    WidthExceeded {
+        /// The requested number of digits
+        width: usize,
+    },
+    /// A fraction has, or would need, more digits than the supported maximum.
+    FractionLimitExceeded {
+        /// Maximum number of fraction digits
+        limit: usize,
+    },
+    /// An input that needs at least one digit is empty, e.g. an empty string
+    /// parsed as a [`BigInt`](crate::BigInt).
+    EmptyInput,
+}
+
+impl Error {
+    /// An [`Error::InvalidCharacter`] for the character of `input` at `byte_offset`
+    pub(crate) fn invalid_character(input: &str, byte_offset: usize) -> Self {
+        Error::InvalidCharacter {
+            ch: input[byte_offset..].chars().next().expect("offset is inside the input"),
+            byte_offset,
+            char_index: input[..byte_offset].chars().count(),
+        }
+    }
+
+    /// Shift the position of an [`Error::InvalidCharacter`] past `prefix`,
+    /// for errors found in the part of an input that follows it
+    pub(crate) fn after_prefix(self, prefix: &str) -> Self {
+        match self {
+            Error::InvalidCharacter {
+                ch,
+                byte_offset,
+                char_index,
+            } => Error::InvalidCharacter {
+                ch,
+                byte_offset: byte_offset + prefix.len(),
+                char_index: char_index + prefix.chars().count(),
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::InvalidCharacter {
+                ch,
+                byte_offset,
+                char_index,
+            } => write!(
+                f,
+                "Input character '{}' not found in src_table (char index {}, byte offset {})",
+                ch, char_index, byte_offset
+            ),
+            Error::InvalidDigit { digit, index, base } => write!(
+                f,
+                "Digit {} at index {} is out of range for base {}",
+                digit, index, base
+            ),
+            Error::InvalidBase { base } => write!(f, "Base {} is invalid, it must be at least 2", base),
+            Error::Overflow { ty } => write!(f, "Value does not fit in {}", ty),
+            Error::NegativeValue => write!(f, "Negative values are not supported"),
+            Error::BaseMismatch { src_base, dst_base } => write!(
+                f,
+                "Source base {} and destination base {} differ",
+                src_base, dst_base
+            ),
+            Error::EmptyTable { table } => write!(f, "{} is empty", table.name()),
+            Error::SingleCharacterTable { table } => write!(
+                f,
+                "{} must contain at least two characters",
+                table.name()
+            ),
+            Error::DuplicateCharacter {
+                table,
+                ch,
+                first,
+                second,
+            } => write!(
+                f,
+                "{} contains duplicate characters: '{}' at index {} and {}",
+                table.name(),
+                ch,
+                first,
+                second
+            ),
+            Error::SizeLimitExceeded {
+                table,
+                limit,
+                actual,
+            } => write!(
+                f,
+                "{} has {} characters, exceeding the limit of {}",
+                table.name(),
+                actual,
+                limit
+            ),
+            Error::BufferTooSmall { needed, available } => write!(
+                f,
+                "Output buffer too small: {} bytes needed, {} available",
+                needed, available
+            ),
+            Error::WriteFailed => write!(f, "Writing the output failed"),
+            Error::SignConflict { table, ch } => write!(
+                f,
+                "Sign character '{}' conflicts with {} or its other symbols",
+                ch,
+                table.name()
+            ),
+            Error::RadixPointConflict { table, ch } => write!(
+                f,
+                "Radix point '{}' conflicts with {} or its other symbols",
+                ch,
+                table.name()
+            ),
+            Error::RepetendConflict { table, ch } => write!(
+                f,
+                "Repetend mark '{}' conflicts with {} or its other symbols",
+                ch,
+                table.name()
+            ),
+            Error::ExponentConflict { table, ch } => write!(
+                f,
+                "Exponent marker '{}' conflicts with {} or its other symbols",
+                ch,
+                table.name()
+            ),
+            Error::FractionalValue => write!(f, "Fractional values are not supported"),
+            Error::RepeatingValue => write!(f, "Repeating fractions are not supported"),
+            Error::ZeroDenominator => write!(f, "Denominator is zero"),
+            Error::NonFiniteValue => write!(f, "Non-finite value conflicts with the table"),
+            Error::WidthExceeded { width } => write!(f, "Value does not fit in {} digits", width),
+            Error::FractionLimitExceeded { limit } => {
+                write!(f, "Fraction has more than {} digits", limit)
+            }
+            Error::EmptyInput => write!(f, "Input is empty"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
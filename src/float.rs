@@ -0,0 +1,482 @@
+use crate::alphabet::Alphabet;
+use crate::big_int::BigInt;
+use crate::converter::Converter;
+use crate::error::{Error, Result};
+use crate::native::NativeFloat;
+use crate::radix;
+use std::cmp::Ordering;
+
+/// Spelling of NaN
+const NAN: &str = "NaN";
+
+/// Spelling of infinity, after the negative sign for negative infinity
+const INFINITY: &str = "∞";
+
+/// Exponents of the leading digit written in fixed notation when scientific
+/// notation is enabled, as in JavaScript
+const FIXED_EXPONENTS: std::ops::Range<i64> = -6..21;
+
+/// Power of two exponent of the least significant bit of subnormal values
+fn min_exp<F: NativeFloat>() -> i64 {
+    i64::from(F::MIN_EXP) - i64::from(F::MANTISSA_DIGITS)
+}
+
+/// Power of two exponent of the least significant bit of the largest values
+fn max_exp<F: NativeFloat>() -> i64 {
+    i64::from(F::MAX_EXP) - i64::from(F::MANTISSA_DIGITS)
+}
+
+/// Splits a finite value into its sign, mantissa and exponent, with
+/// `value = mantissa * 2^exp`
+///
+/// The mantissa has exactly `F::MANTISSA_DIGITS` bits, unless the value is
+/// subnormal or zero, in which case the exponent is the minimum.
+fn decompose<F: NativeFloat>(value: F) -> (bool, u64, i64) {
+    let bits = value.to_f64().to_bits();
+    let negative = bits >> 63 == 1;
+    let biased = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mut mantissa, mut exp) = match biased {
+        0 => (fraction, -1074),
+        _ => (fraction | 1 << 52, biased - 1075),
+    };
+
+    // `F` represents the value exactly, so no set bits are shifted out
+    if mantissa != 0 {
+        let len = i64::from(u64::BITS - mantissa.leading_zeros());
+        let shift = (len - i64::from(F::MANTISSA_DIGITS)).max(min_exp::<F>() - exp);
+        if shift >= 0 {
+            mantissa >>= shift;
+        } else {
+            mantissa <<= -shift;
+        }
+        exp += shift;
+    }
+    (negative, mantissa, exp)
+}
+
+/// Inverse of [`decompose`]; `mantissa * 2^exp` must be representable in `F`
+fn compose<F: NativeFloat>(negative: bool, mantissa: u64, exp: i64) -> F {
+    let exp = exp as i32;
+    let scale = if exp >= -1022 {
+        f64::from_bits(((exp + 1023) as u64) << 52)
+    } else {
+        f64::from_bits(1 << (exp + 1074))
+    };
+    // Both factors and their product are exact in `f64`
+    let magnitude = mantissa as f64 * scale;
+    F::from_f64(if negative { -magnitude } else { magnitude })
+}
+
+/// `2^exp` as a `BigInt`
+fn pow2(exp: i64) -> BigInt {
+    BigInt::from(2u8).pow(u32::try_from(exp).expect("exponent out of range"))
+}
+
+/// Shortest digits that round to `mantissa * 2^exp` in the format of `F`,
+/// using Burger and Dybvig's free-format algorithm
+///
+/// # Returns
+///
+/// The digits `d1 d2 ... dn` and the exponent `k` such that the value is
+/// `0.d1d2...dn * base^k`. The last digit is never zero.
+fn shortest_digits<F: NativeFloat>(mantissa: u64, exp: i64, base: u32) -> (Vec<u32>, i64) {
+    // Parsing rounds ties to even, so the bounds of the rounding interval of
+    // an even mantissa round to it
+    let inclusive = mantissa.is_multiple_of(2);
+    // The gap to the next lower value is half as wide below a power of two,
+    // except at the smallest normal value
+    let boundary = mantissa == 1 << (F::MANTISSA_DIGITS - 1) && exp > min_exp::<F>();
+    let shift = if boundary { 2 } else { 1 };
+
+    // The value is r / s, and values in (r - m_minus, r + m_plus) / s round
+    // to it
+    let mut r = BigInt::from(mantissa) * pow2(exp.max(0) + shift);
+    let mut s = pow2((-exp).max(0) + shift);
+    let mut m_plus = pow2(exp.max(0) + shift - 1);
+    let mut m_minus = pow2(exp.max(0));
+
+    // Find the smallest k with r + m_plus below s * base^k, starting from an
+    // estimate that may be off by one either way
+    let fits = |r: &BigInt, m_plus: &BigInt, s: &BigInt| {
+        let high = r + m_plus;
+        if inclusive { high < *s } else { high <= *s }
+    };
+    let len = i64::from(u64::BITS - mantissa.leading_zeros()) + exp;
+    let mut k = ((len - 1) as f64 / f64::from(base).log2()).ceil() as i64;
+    let scale = BigInt::from(base).pow(u32::try_from(k.unsigned_abs()).expect("k is small"));
+    if k >= 0 {
+        s *= scale;
+    } else {
+        r *= &scale;
+        m_plus *= &scale;
+        m_minus *= scale;
+    }
+    while !fits(&r, &m_plus, &s) {
+        s.mul_small(base);
+        k += 1;
+    }
+    loop {
+        let mut lower_r = r.clone();
+        lower_r.mul_small(base);
+        let mut lower_m_plus = m_plus.clone();
+        lower_m_plus.mul_small(base);
+        if !fits(&lower_r, &lower_m_plus, &s) {
+            break;
+        }
+        r = lower_r;
+        m_plus = lower_m_plus;
+        m_minus.mul_small(base);
+        k -= 1;
+    }
+
+    // Generate digits until the prefix lies within the rounding interval
+    let mut digits = Vec::new();
+    loop {
+        r.mul_small(base);
+        m_plus.mul_small(base);
+        m_minus.mul_small(base);
+        let (digit, rem) = r.div_rem(&s);
+        r = rem;
+        let digit = u32::try_from(&digit).expect("digit is less than the base");
+
+        let low = if inclusive { r <= m_minus } else { r < m_minus };
+        let high = !fits(&r, &m_plus, &s);
+        let round_up = match (low, high) {
+            (false, false) => {
+                digits.push(digit);
+                continue;
+            }
+            (true, false) => false,
+            (false, true) => true,
+            // Both digits are within the interval; take the closer one, or
+            // the larger on a tie, as the standard library does
+            (true, true) => {
+                let mut twice = r.clone();
+                twice.mul_small(2);
+                twice >= s
+            }
+        };
+        digits.push(digit + u32::from(round_up));
+        return (digits, k);
+    }
+}
+
+/// Rounds `numerator / denominator * base^exp` to the nearest value of `F`,
+/// ties to even
+fn round_to_float<F: NativeFloat>(
+    negative: bool,
+    numerator: BigInt,
+    denominator: BigInt,
+    base: u32,
+    exp: i64,
+) -> F {
+    let infinity = F::from_f64(if negative {
+        f64::NEG_INFINITY
+    } else {
+        f64::INFINITY
+    });
+    if numerator.is_zero() {
+        return compose(negative, 0, 0);
+    }
+
+    // Settle values far outside the range of `F` without computing
+    // base^exp; the estimate is within one of log2 of the value
+    let log2 =
+        numerator.bits() as f64 - denominator.bits() as f64 + exp as f64 * f64::from(base).log2();
+    if log2 > f64::from(F::MAX_EXP) + 1.0 {
+        return infinity;
+    }
+    if log2 < (min_exp::<F>() - 2) as f64 {
+        return compose(negative, 0, 0);
+    }
+
+    let scale =
+        BigInt::from(base).pow(u32::try_from(exp.unsigned_abs()).expect("exponent out of range"));
+    let (numerator, denominator) = if exp >= 0 {
+        (numerator * scale, denominator)
+    } else {
+        (numerator, denominator * scale)
+    };
+
+    // Find the exponent that leaves a quotient of exactly the precision of
+    // `F`, or less for subnormal values
+    let precision = u64::from(F::MANTISSA_DIGITS);
+    let mut exp = (numerator.bits() as i64 - denominator.bits() as i64 - precision as i64)
+        .max(min_exp::<F>());
+    let (quotient, rem, divisor) = loop {
+        let (n, d) = if exp >= 0 {
+            (numerator.clone(), &denominator * pow2(exp))
+        } else {
+            (&numerator * pow2(-exp), denominator.clone())
+        };
+        let (quotient, rem) = n.div_rem(&d);
+        match quotient.bits().cmp(&precision) {
+            Ordering::Greater => exp += 1,
+            Ordering::Less if exp > min_exp::<F>() => exp -= 1,
+            _ => break (quotient, rem, d),
+        }
+    };
+
+    let mut mantissa = u64::try_from(&quotient).expect("mantissa fits in 64 bits");
+    let mut twice = rem;
+    twice.mul_small(2);
+    let round_up = match twice.cmp(&divisor) {
+        Ordering::Less => false,
+        Ordering::Greater => true,
+        Ordering::Equal => mantissa % 2 == 1,
+    };
+    if round_up {
+        mantissa += 1;
+        if mantissa == 1 << precision {
+            mantissa >>= 1;
+            exp += 1;
+        }
+    }
+    if exp > max_exp::<F>() {
+        return infinity;
+    }
+    compose(negative, mantissa, exp)
+}
+
+impl Alphabet {
+    /// Formats a floating-point value with the fewest digits that parse back
+    /// to the same value.
+    ///
+    /// Values are written in fixed notation, or in scientific notation when
+    /// an exponent marker is configured and the exponent of the leading digit
+    /// is below -6 or above 20, as in JavaScript. The exponent is a power of
+    /// the base, written in the same table. Scientific notation also needs a
+    /// negative sign for negative exponents and a radix point for more than
+    /// one digit; otherwise fixed notation is used.
+    ///
+    /// NaN is written as `NaN` and infinity as `∞`. Negative zero keeps its
+    /// sign if the alphabet has a negative sign, and is written as `0`
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeValue`] or [`Error::FractionalValue`] if the
+    /// value needs a sign or a radix point that is not configured, or
+    /// [`Error::NonFiniteValue`] if the value is NaN or infinite and its
+    /// spelling could be read as a number in this alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::Alphabet;
+    ///
+    /// let dec = Alphabet::new("0123456789").unwrap()
+    ///     .with_signs('-', None).unwrap()
+    ///     .with_radix_point('.').unwrap()
+    ///     .with_exponent_marker('e').unwrap();
+    /// assert_eq!(dec.format_float(0.1f64).unwrap(), "0.1");
+    /// assert_eq!(dec.format_float(0.1f32).unwrap(), "0.1");
+    /// assert_eq!(dec.format_float(-1e21f64).unwrap(), "-1e21");
+    /// assert_eq!(dec.format_float(5e-324f64).unwrap(), "5e-324");
+    /// assert_eq!(dec.format_float(f64::NEG_INFINITY).unwrap(), "-∞");
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap().with_radix_point('.').unwrap();
+    /// assert_eq!(hex.format_float(0.1f64).unwrap(), "0.1999999999999a");
+    /// assert_eq!(hex.format_float(255.5f32).unwrap(), "ff.8");
+    /// ```
+    pub fn format_float<F: NativeFloat>(&self, value: F) -> Result<String> {
+        let float = value.to_f64();
+        let negative = float.is_sign_negative();
+        if float.is_nan() {
+            return self.format_special(false, NAN);
+        }
+        if float.is_infinite() {
+            return self.format_special(negative, INFINITY);
+        }
+
+        let mut out = String::new();
+        let (_, mantissa, exp) = decompose(value);
+        if mantissa == 0 {
+            out.extend(self.negative_sign().filter(|_| negative));
+            out.push(self.zero_char());
+            return Ok(out);
+        }
+        out.extend(self.sign_char(negative)?);
+        let base = self.base() as u32;
+        let (digits, k) = shortest_digits::<F>(mantissa, exp, base);
+
+        let leading_exp = k - 1;
+        let scientific = !FIXED_EXPONENTS.contains(&leading_exp)
+            && (leading_exp >= 0 || self.negative_sign().is_some())
+            && (digits.len() == 1 || self.radix_point().is_some());
+        match self.exponent_marker() {
+            Some(marker) if scientific => {
+                self.render_into(&digits[..1], &mut out);
+                if digits.len() > 1 {
+                    out.extend(self.radix_point());
+                    self.render_into(&digits[1..], &mut out);
+                }
+                out.push(marker);
+                out.extend(self.sign_char(leading_exp < 0)?);
+                let exp_digits = radix::to_digits(BigInt::from(leading_exp.unsigned_abs()), base);
+                self.render_into(&exp_digits, &mut out);
+            }
+            _ if k <= 0 => {
+                let point = self.radix_point().ok_or(Error::FractionalValue)?;
+                out.push(self.zero_char());
+                out.push(point);
+                out.extend(std::iter::repeat_n(
+                    self.zero_char(),
+                    k.unsigned_abs() as usize,
+                ));
+                self.render_into(&digits, &mut out);
+            }
+            _ => {
+                let integer_len = k as usize;
+                if integer_len < digits.len() {
+                    let point = self.radix_point().ok_or(Error::FractionalValue)?;
+                    self.render_into(&digits[..integer_len], &mut out);
+                    out.push(point);
+                    self.render_into(&digits[integer_len..], &mut out);
+                } else {
+                    self.render_into(&digits, &mut out);
+                    out.extend(std::iter::repeat_n(
+                        self.zero_char(),
+                        integer_len - digits.len(),
+                    ));
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Parses a number into the nearest floating-point value, rounding ties
+    /// to even.
+    ///
+    /// The input may have a sign, a radix point and, if an exponent marker is
+    /// configured, a signed exponent that is a power of the base. Values too
+    /// large for `F` parse as infinity and values too small as zero, keeping
+    /// their sign. `NaN` and `∞` parse as NaN and infinity unless they could
+    /// be read as a number in this alphabet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the table, a misplaced symbol, or an exponent marker
+    /// without exponent digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::Alphabet;
+    ///
+    /// let bin = Alphabet::new("01").unwrap()
+    ///     .with_signs('-', None).unwrap()
+    ///     .with_radix_point('.').unwrap()
+    ///     .with_exponent_marker('p').unwrap();
+    /// assert_eq!(bin.parse_float::<f64>("-1.1p-10").unwrap(), -0.375);
+    /// assert_eq!(bin.parse_float::<f64>("1p10000000000").unwrap(), f64::INFINITY);
+    /// assert!(bin.parse_float::<f64>("-0").unwrap().is_sign_negative());
+    /// assert!(bin.parse_float::<f32>("NaN").unwrap().is_nan());
+    /// ```
+    pub fn parse_float<F: NativeFloat>(&self, input: &str) -> Result<F> {
+        let (negative, sign, unsigned) = self.split_sign(input);
+        if unsigned == NAN && self.is_special(NAN) {
+            return Ok(F::from_f64(f64::NAN));
+        }
+        if unsigned == INFINITY && self.is_special(INFINITY) {
+            return Ok(F::from_f64(if negative {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }));
+        }
+
+        let (significand, exponent) = match self.exponent_marker() {
+            Some(marker) => match unsigned.split_once(marker) {
+                Some((significand, exponent)) => (significand, Some((marker, exponent))),
+                None => (unsigned, None),
+            },
+            None => (unsigned, None),
+        };
+        let (numerator, denominator) =
+            self.parse_fraction(&input[..sign.len() + significand.len()])?;
+        let exp = match exponent {
+            Some((marker, exponent)) => {
+                let exponent_start = input.len() - exponent.len();
+                let (exp_negative, exp_sign, digits) = self.split_sign(exponent);
+                if digits.is_empty() {
+                    let marker_start = exponent_start - marker.len_utf8();
+                    return Err(Error::invalid_character(input, marker_start));
+                }
+                let magnitude = self
+                    .parse_unsigned(digits)
+                    .map_err(|e| e.after_prefix(&input[..exponent_start + exp_sign.len()]))?;
+                // Larger exponents over- or underflow all the same
+                let magnitude = i64::try_from(&magnitude).unwrap_or(i64::MAX);
+                if exp_negative { -magnitude } else { magnitude }
+            }
+            None => 0,
+        };
+        Ok(round_to_float(
+            negative,
+            numerator.abs(),
+            denominator,
+            self.base() as u32,
+            exp,
+        ))
+    }
+
+    /// Writes NaN or infinity with the given sign
+    fn format_special(&self, negative: bool, spelling: &str) -> Result<String> {
+        if !self.is_special(spelling) {
+            return Err(Error::NonFiniteValue);
+        }
+        let mut out = String::new();
+        out.extend(self.sign_char(negative)?);
+        out.push_str(spelling);
+        Ok(out)
+    }
+
+    /// Whether `spelling` cannot be read as a number, because it has a
+    /// character that is neither in the table nor a configured symbol
+    fn is_special(&self, spelling: &str) -> bool {
+        spelling.chars().any(|ch| !self.is_in_use(ch))
+    }
+}
+
+impl Converter {
+    /// Formats a floating-point value in the destination table with the
+    /// fewest digits that parse back to the same value.
+    ///
+    /// See [`Alphabet::format_float`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Alphabet::format_float`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter};
+    ///
+    /// let converter = Converter::new(base::DEC, "0123456789abcdefghijklmnopqrstuvwxyz")
+    ///     .with_signs('-', None).unwrap()
+    ///     .with_radix_point('.').unwrap()
+    ///     .with_exponent_marker('^').unwrap();
+    /// assert_eq!(converter.format_float(0.5f64).unwrap(), "0.i");
+    /// assert_eq!(converter.format_float(-1e300f64).unwrap(), "-f.hgyjdfcg6j^5c");
+    /// assert_eq!(converter.inverse().parse_float::<f64>("-f.hgyjdfcg6j^5c").unwrap(), -1e300);
+    /// ```
+    pub fn format_float<F: NativeFloat>(&self, value: F) -> Result<String> {
+        self.dst_alphabet().format_float(value)
+    }
+
+    /// Parses a number in the source table into the nearest floating-point
+    /// value.
+    ///
+    /// See [`Alphabet::parse_float`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Alphabet::parse_float`].
+    pub fn parse_float<F: NativeFloat>(&self, input: &str) -> Result<F> {
+        self.src_alphabet().parse_float(input)
+    }
+}
@@ -0,0 +1,277 @@
+use crate::alphabet::Alphabet;
+use crate::big_int::BigInt;
+use crate::converter::Converter;
+use crate::error::{Error, Result};
+use crate::radix;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Most fraction digits [`Converter::convert_fraction`] parses or produces
+///
+/// The denominator of a value with `k` fraction digits is `base^k`, so this
+/// bounds the size of the numbers it computes with. The same bound applies to
+/// the width of [`Alphabet::encode_twos_complement`].
+pub const MAX_FRACTION_DIGITS: usize = 100_000;
+
+/// How [`Converter::convert_fraction`] rounds values that need more fraction
+/// digits than the requested precision.
+///
+/// Directions are relative to the value, so [`Rounding::HalfUp`] rounds `-0.5`
+/// to `-1` while [`Rounding::Ceiling`] rounds it to `0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Drop the excess digits, rounding toward zero
+    Truncate,
+    /// Round to the nearest value; ties go to an even last digit
+    #[default]
+    HalfEven,
+    /// Round to the nearest value; ties go away from zero
+    HalfUp,
+    /// Round toward positive infinity
+    Ceiling,
+    /// Round toward negative infinity
+    Floor,
+}
+
+impl Rounding {
+    /// Whether the truncated magnitude `quotient` must be incremented, given
+    /// the non-zero remainder `rem` of its division by `divisor`
+    fn rounds_away(
+        self,
+        negative: bool,
+        quotient: &BigInt,
+        rem: &BigInt,
+        divisor: &BigInt,
+        base: u32,
+    ) -> bool {
+        let half = || {
+            let mut twice = rem.clone();
+            twice.mul_small(2);
+            twice.cmp(divisor)
+        };
+        match self {
+            Rounding::Truncate => false,
+            Rounding::Ceiling => !negative,
+            Rounding::Floor => negative,
+            Rounding::HalfUp => half() != Ordering::Less,
+            Rounding::HalfEven => match half() {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                // The parity of the last digit differs from that of the
+                // quotient in odd bases
+                Ordering::Equal => quotient.clone().div_mod_small(base) % 2 == 1,
+            },
+        }
+    }
+}
+
+/// Result of [`Converter::convert_fraction`]: the formatted value and
+/// whether it equals the input exactly.
+///
+/// # Examples
+///
+/// ```
+/// use anybase::{base, Converter, Rounding};
+///
+/// let converter = Converter::new(base::DEC, base::BIN).with_radix_point('.').unwrap();
+/// let output = converter.convert_fraction("0.1", 8, Rounding::HalfEven).unwrap();
+/// assert_eq!(output.as_str(), "0.0001101");
+/// assert!(!output.is_exact());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FractionOutput {
+    text: String,
+    exact: bool,
+}
+
+impl FractionOutput {
+    /// Returns the formatted value.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns `true` if no rounding was needed, i.e. the output represents
+    /// the input value exactly.
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// Returns the formatted value, consuming the output.
+    pub fn into_string(self) -> String {
+        self.text
+    }
+}
+
+impl fmt::Display for FractionOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl Alphabet {
+    /// Parses a number with an optional sign and radix point into the exact
+    /// fraction `numerator / denominator`
+    ///
+    /// The denominator is `base^k` for `k` fraction digits; the numerator
+    /// carries the sign. Either part of the input may be empty, but not both
+    /// when there is a radix point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`](crate::Error::InvalidCharacter) if
+    /// `input` contains a character that is not in the table, a second
+    /// radix point, a radix point with no digits on either side, or a sign
+    /// with nothing after it, or
+    /// [`Error::FractionLimitExceeded`] if it has more than
+    /// [`MAX_FRACTION_DIGITS`] fraction digits.
+    pub(crate) fn parse_fraction(&self, input: &str) -> Result<(BigInt, BigInt)> {
+        let (negative, sign, unsigned) = self.split_sign_strict(input)?;
+        let (integer, fraction) = match self.radix_point().and_then(|p| unsigned.split_once(p)) {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (unsigned, ""),
+        };
+        if integer.is_empty() && fraction.is_empty() && !unsigned.is_empty() {
+            return Err(Error::invalid_character(input, sign.len()));
+        }
+
+        let mut digits = self
+            .decode_digits(integer)
+            .map_err(|e| e.after_prefix(sign))?;
+        let fraction_start = input.len() - fraction.len();
+        let fraction_digits = self
+            .decode_digits(fraction)
+            .map_err(|e| e.after_prefix(&input[..fraction_start]))?;
+        digits.extend(&fraction_digits);
+
+        let base = self.base() as u32;
+        let exp = exponent(fraction_digits.len())?;
+        let numerator = radix::from_digits(&digits, base);
+        let denominator = BigInt::from(base).pow(exp);
+        Ok((if negative { -numerator } else { numerator }, denominator))
+    }
+
+    /// Appends `magnitude / base^precision` with the given sign to `out`,
+    /// without trailing zero fraction digits
+    ///
+    /// `precision` must be zero if this alphabet has no radix point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeValue`](crate::Error::NegativeValue) if the
+    /// value is negative and this alphabet has no negative sign. Nothing is
+    /// written on error.
+    pub(crate) fn format_fixed(
+        &self,
+        negative: bool,
+        magnitude: BigInt,
+        precision: usize,
+        out: &mut String,
+    ) -> Result<()> {
+        let sign = self.sign_char(negative && !magnitude.is_zero())?;
+        let mut digits = radix::to_digits(magnitude, self.base() as u32);
+        if digits.len() <= precision {
+            let padding = precision + 1 - digits.len();
+            digits.splice(0..0, std::iter::repeat_n(0, padding));
+        }
+        let (integer, fraction) = digits.split_at(digits.len() - precision);
+        let fraction_len = fraction.len() - fraction.iter().rev().take_while(|&&d| d == 0).count();
+
+        out.extend(sign);
+        self.render_into(integer, out);
+        if fraction_len > 0 {
+            out.push(
+                self.radix_point()
+                    .expect("precision requires a radix point"),
+            );
+            self.render_into(&fraction[..fraction_len], out);
+        }
+        Ok(())
+    }
+}
+
+/// A number of fraction digits as the exponent of the denominator
+///
+/// # Errors
+///
+/// Returns [`Error::FractionLimitExceeded`] if `len` is more than
+/// [`MAX_FRACTION_DIGITS`].
+pub(crate) fn exponent(len: usize) -> Result<u32> {
+    if len > MAX_FRACTION_DIGITS {
+        return Err(Error::FractionLimitExceeded {
+            limit: MAX_FRACTION_DIGITS,
+        });
+    }
+    Ok(len as u32)
+}
+
+impl Converter {
+    /// Converts a number with a fraction part, rounding it to at most
+    /// `precision` fraction digits in the destination base.
+    ///
+    /// The input may contain the source radix point and, if signs are
+    /// enabled, a leading sign. The output has no trailing zero fraction
+    /// digits and no radix point if the fraction part is zero. If the
+    /// destination table has no radix point, the value is rounded to an
+    /// integer. A value that rounds to zero is never signed.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The number to convert, e.g. `"ff.8"`
+    /// * `precision` - The maximum number of fraction digits of the output
+    /// * `rounding` - How to round values that need more fraction digits
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`](crate::Error::InvalidCharacter) if
+    /// `input` contains a character that is not in the source table,
+    /// including a second radix point or one with no digits on either side,
+    /// [`Error::NegativeValue`](crate::Error::NegativeValue) if the result is
+    /// negative and the destination table has no negative sign, or
+    /// [`Error::FractionLimitExceeded`](crate::Error::FractionLimitExceeded)
+    /// if `precision` or the number of fraction digits of `input` is more
+    /// than [`MAX_FRACTION_DIGITS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter, Rounding};
+    ///
+    /// let converter = Converter::new(base::HEX, base::DEC).with_radix_point('.').unwrap();
+    /// let output = converter.convert_fraction("ff.8", 10, Rounding::HalfEven).unwrap();
+    /// assert_eq!(output.as_str(), "255.5");
+    /// assert!(output.is_exact());
+    ///
+    /// let output = converter.convert_fraction("0.1", 3, Rounding::Truncate).unwrap();
+    /// assert_eq!(output.as_str(), "0.062");
+    /// assert!(!output.is_exact());
+    /// ```
+    pub fn convert_fraction(
+        &self,
+        input: &str,
+        precision: usize,
+        rounding: Rounding,
+    ) -> Result<FractionOutput> {
+        let (numerator, denominator) = self.src_alphabet().parse_fraction(input)?;
+        let dst = self.dst_alphabet();
+        let precision = if dst.radix_point().is_some() {
+            precision
+        } else {
+            0
+        };
+        let base = dst.base() as u32;
+        let exp = exponent(precision)?;
+
+        // Rounding applies to the magnitude scaled by base^precision
+        let negative = numerator.is_negative();
+        let scaled = numerator.abs() * BigInt::from(base).pow(exp);
+        let (mut quotient, rem) = scaled.div_rem(&denominator);
+        let exact = rem.is_zero();
+        if !exact && rounding.rounds_away(negative, &quotient, &rem, &denominator, base) {
+            quotient.add_small(1);
+        }
+
+        let mut text = String::new();
+        dst.format_fixed(negative, quotient, precision, &mut text)?;
+        Ok(FractionOutput { text, exact })
+    }
+}
@@ -8,8 +8,15 @@ its custom BigInt implementation that avoids overflow issues.
 ## Features
 
 - Convert between any two bases using custom character tables
-- Supports arbitrarily large integers
+- Reusable, validated [`Alphabet`]s shared between converters
+- Supports arbitrarily large integers, with a public [`BigInt`] for arithmetic
+- Signed values with configurable sign characters, two's complement and zigzag
+- Fractional values with a configurable radix point and rounding modes
+- Exact rational conversion with the repeating digits marked
+- Shortest round-trip `f32`/`f64` formatting and correctly rounded parsing
 - Optimized performance with limb-based arithmetic
+- Subquadratic divide-and-conquer conversion for very large numbers
+- Cached converters for the functional API
 - No external dependencies
 
 ## Examples
@@ -27,27 +34,58 @@ let result = converter.convert("1010").unwrap();
 assert_eq!(result, "10");
 ```
 
+## Cargo features
+
+- `parallel`: converts the two halves of each divide-and-conquer step on
+  separate threads for numbers with tens of thousands of digits or more,
+  using only `std::thread`. The output is identical to the serial build.
+
 ## Performance
 
-The library uses a limb-based BigInt implementation with a radix of u32
-to efficiently handle large numbers while avoiding overflow. Arithmetic operations
-use u64 intermediates for additional safety.
+The library uses a limb-based BigInt implementation with 32-bit limbs and a
+radix of 2^32, so carries are plain shifts and masks. Arithmetic operations
+use u64 intermediates, which cannot overflow.
 */
 
 //! High-performance arbitrary base integer conversion (optimized version)
-//! - Uses limb base = u32::MAX
+//! - Uses limb base = 2^32
 //! - Uses u64 as intermediate to avoid overflow
 
+mod alphabet;
+mod batch;
 mod big_int;
+mod bytes;
+pub mod cache;
 mod converter;
+mod error;
+mod float;
+mod fraction;
+mod native;
+mod radix;
+mod rational;
+mod signed;
+mod strategy;
+#[cfg(test)]
+mod test_rng;
 
+pub use alphabet::Alphabet;
+pub use batch::BatchOutput;
+pub use big_int::BigInt;
+pub use bytes::ByteOrder;
 pub use converter::*;
+pub use error::{Error, Result, Table};
+pub use fraction::{FractionOutput, MAX_FRACTION_DIGITS, Rounding};
+pub use native::{NativeFloat, NativeInt};
+pub use radix::Digits;
 
 /// Concise functional interface for base conversion
 ///
 /// Converts a number represented as a string in one base to its equivalent
 /// in another base, using custom character tables for both bases.
 ///
+/// Converters for recently used table pairs are kept in a bounded cache, so
+/// repeated calls do not rebuild the tables; see [`cache`].
+///
 /// # Arguments
 ///
 /// * `input` - The input number as a string
@@ -56,7 +94,7 @@ pub use converter::*;
 ///
 /// # Returns
 ///
-/// Result containing the converted string or an error message
+/// Result containing the converted string or an [`Error`]
 ///
 /// # Examples
 ///
@@ -75,12 +113,26 @@ pub use converter::*;
 /// # Errors
 ///
 /// Returns an error if:
-/// - src_table or dst_table is empty
-/// - src_table contains duplicate characters
+/// - src_table or dst_table is empty or has a single character
+/// - src_table or dst_table contains duplicate characters
 /// - input contains characters not in src_table
-pub fn convert_base(input: &str, src_table: &str, dst_table: &str) -> Result<String, String> {
-    let converter = Converter::new(src_table, dst_table);
-    converter.convert(input)
+pub fn convert_base(input: &str, src_table: &str, dst_table: &str) -> Result<String> {
+    cache::converter(src_table, dst_table)?.convert(input)
+}
+
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench {
+    //! Internal entry points used by the benchmarks; not part of the public API.
+
+    use crate::{radix, Alphabet, Result};
+
+    /// Converts using only the quadratic digit-by-digit algorithms
+    pub fn convert_quadratic(input: &str, src: &Alphabet, dst: &Alphabet) -> Result<String> {
+        let digits = src.decode_digits(input)?;
+        let value = radix::from_digits_naive(&digits, src.base() as u32);
+        dst.encode_digits(&radix::to_digits_naive(value, dst.base() as u32))
+    }
 }
 
 pub mod base {
@@ -153,9 +205,22 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "dst_table contains duplicate characters")]
     fn test_duplicate_chars_in_table() {
-        convert_base("123", "0123456789", "011").unwrap();
+        assert_eq!(
+            convert_base("123", "0123456789", "011"),
+            Err(Error::DuplicateCharacter {
+                table: Table::Destination,
+                ch: '1',
+                first: 1,
+                second: 2,
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "src_table contains duplicate characters")]
+    fn test_new_panics_on_invalid_table() {
+        Converter::new("00", "01");
     }
 
     #[test]
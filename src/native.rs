@@ -0,0 +1,122 @@
+/// Primitive integer types that can be encoded and decoded directly.
+///
+/// Implemented for `u8`, `u16`, `u32`, `u64`, `u128`, `usize` and their signed
+/// counterparts. Conversions through this trait use native arithmetic only and
+/// never allocate a `BigInt`.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait NativeInt: Copy + private::Sealed {
+    /// Name of the type, used in [`Error::Overflow`](crate::Error::Overflow).
+    const NAME: &'static str;
+
+    /// Returns the value as `u128`, or `None` if it is negative.
+    fn to_u128(self) -> Option<u128>;
+
+    /// Returns `value` as `Self`, or `None` if it does not fit.
+    fn from_u128(value: u128) -> Option<Self>;
+
+    /// Returns whether the value is negative, and its absolute value.
+    fn to_sign_magnitude(self) -> (bool, u128);
+
+    /// Returns the value with the given sign and absolute value as `Self`,
+    /// or `None` if it does not fit.
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_native_int {
+    ($($t:ty),*) => {$(
+        impl private::Sealed for $t {}
+
+        impl NativeInt for $t {
+            const NAME: &'static str = stringify!($t);
+
+            #[inline]
+            fn to_u128(self) -> Option<u128> {
+                u128::try_from(self).ok()
+            }
+
+            #[inline]
+            fn from_u128(value: u128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            #[inline]
+            fn to_sign_magnitude(self) -> (bool, u128) {
+                match u128::try_from(self) {
+                    Ok(v) => (false, v),
+                    // Only reached for negative values of signed types
+                    Err(_) => (true, (self as i128).unsigned_abs()),
+                }
+            }
+
+            #[inline]
+            fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                if !negative {
+                    return Self::from_u128(magnitude);
+                }
+                0i128
+                    .checked_sub_unsigned(magnitude)
+                    .and_then(|v| <$t>::try_from(v).ok())
+            }
+        }
+    )*};
+}
+
+impl_native_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Primitive floating-point types that can be formatted and parsed in any
+/// base.
+///
+/// Implemented for `f32` and `f64`. Conversions are exact in the binary
+/// format of the type: formatting produces the shortest digits that parse
+/// back to the same value, and parsing rounds to the nearest value.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait NativeFloat: Copy + private::Sealed {
+    /// Number of significant bits, including the implicit leading bit, as in
+    /// [`f64::MANTISSA_DIGITS`].
+    const MANTISSA_DIGITS: u32;
+
+    /// One greater than the smallest normal power of two exponent, as in
+    /// [`f64::MIN_EXP`].
+    const MIN_EXP: i32;
+
+    /// One greater than the largest power of two exponent, as in
+    /// [`f64::MAX_EXP`].
+    const MAX_EXP: i32;
+
+    /// Returns the value as `f64`, which is always exact.
+    fn to_f64(self) -> f64;
+
+    /// Returns `value` as `Self`, rounding to the nearest value if it is not
+    /// representable.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_native_float {
+    ($($t:ident),*) => {$(
+        impl private::Sealed for $t {}
+
+        impl NativeFloat for $t {
+            const MANTISSA_DIGITS: u32 = $t::MANTISSA_DIGITS;
+            const MIN_EXP: i32 = $t::MIN_EXP;
+            const MAX_EXP: i32 = $t::MAX_EXP;
+
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+impl_native_float!(f32, f64);
@@ -0,0 +1,469 @@
+//! Radix conversion between digit sequences and BigInt
+//!
+//! Small numbers use the quadratic digit-by-digit algorithms. Large numbers
+//! use divide-and-conquer: parsing splits the digits into halves and combines
+//! them with precomputed powers of the base, and formatting recursively splits
+//! the value by those powers. With subquadratic multiplication and division in
+//! [`BigInt`] both directions run in O(M(n) log n).
+//!
+//! With the `parallel` feature the two halves of large steps run on separate
+//! threads, bounded by the available parallelism.
+
+use crate::big_int::BigInt;
+
+/// Limb count below which the quadratic digit-by-digit algorithms are used
+const DC_THRESHOLD_LIMBS: usize = 32;
+
+/// Digit count from which the two halves of a divide-and-conquer step are
+/// converted on separate threads
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD_DIGITS: usize = 1 << 15;
+
+/// The largest number of digits `k` such that `base^k` fits in a limb
+///
+/// The quadratic algorithms process `k` digits per limb pass instead of one.
+#[derive(Clone, Copy, Debug)]
+struct LimbChunk {
+    base: u32,
+    /// Digits per chunk
+    digits: usize,
+    /// `base^digits`
+    power: u32,
+}
+
+impl LimbChunk {
+    fn new(base: u32) -> Self {
+        let mut digits = 1;
+        let mut power = u64::from(base);
+        while power * u64::from(base) <= u64::from(u32::MAX) {
+            power *= u64::from(base);
+            digits += 1;
+        }
+        LimbChunk {
+            base,
+            digits,
+            power: power as u32,
+        }
+    }
+
+    /// Value of up to `digits` digits, most significant first
+    fn value(self, digits: &[u32]) -> u32 {
+        // Cannot overflow: the result is below base^digits.len() <= power
+        digits.iter().fold(0, |acc, &d| acc * self.base + d)
+    }
+
+    /// `base^len` for `len <= digits`
+    fn power_of(self, len: usize) -> u32 {
+        if len == self.digits {
+            self.power
+        } else {
+            self.base.pow(len as u32)
+        }
+    }
+}
+
+/// Powers `base^(chunk * 2^i)` used to split and combine digit blocks
+struct Powers {
+    limb: LimbChunk,
+    /// Number of digits in the smallest block
+    chunk: usize,
+    pows: Vec<BigInt>,
+}
+
+impl Powers {
+    fn new(base: u32) -> Self {
+        let limb = LimbChunk::new(base);
+        Powers {
+            limb,
+            chunk: limb.digits * DC_THRESHOLD_LIMBS,
+            pows: Vec::new(),
+        }
+    }
+
+    /// Returns `base^(chunk * 2^level)`, computing it by repeated squaring
+    fn get(&mut self, level: usize) -> &BigInt {
+        if self.pows.is_empty() {
+            let mut p = BigInt::zero();
+            p.add_small(1);
+            for _ in 0..DC_THRESHOLD_LIMBS {
+                p.mul_small(self.limb.power);
+            }
+            self.pows.push(p);
+        }
+        while self.pows.len() <= level {
+            let last = self.pows.last().unwrap();
+            let square = last * last;
+            self.pows.push(square);
+        }
+        &self.pows[level]
+    }
+
+    /// Level of the low block split off from `n > chunk` digits: the
+    /// largest `level` with `chunk * 2^level < n`
+    fn split_level(&self, n: usize) -> usize {
+        let mut level = 0;
+        while self.chunk << (level + 1) < n {
+            level += 1;
+        }
+        level
+    }
+}
+
+/// Number of threads the divide-and-conquer algorithms may use
+fn available_threads() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        std::thread::available_parallelism().map_or(1, |n| n.get())
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+/// Run `a` and `b`, which process `digits` digits together, and return both
+/// results
+///
+/// With the `parallel` feature, large enough steps run `a` on a new thread
+/// and split the thread budget between the two. Each closure receives the
+/// number of threads it may use.
+fn join<RA: Send, RB>(
+    threads: usize,
+    digits: usize,
+    a: impl FnOnce(usize) -> RA + Send,
+    b: impl FnOnce(usize) -> RB,
+) -> (RA, RB) {
+    #[cfg(feature = "parallel")]
+    if threads > 1 && digits >= PARALLEL_THRESHOLD_DIGITS {
+        let a_threads = threads / 2;
+        return std::thread::scope(|s| {
+            let handle = s.spawn(move || a(a_threads));
+            let rb = b(threads - a_threads);
+            let ra = handle.join().unwrap_or_else(|e| std::panic::resume_unwind(e));
+            (ra, rb)
+        });
+    }
+    let _ = digits;
+    (a(threads), b(threads))
+}
+
+/// Convert digits (most significant first, each less than `base`) to a BigInt
+pub(crate) fn from_digits(digits: &[u32], base: u32) -> BigInt {
+    let mut value = BigInt::zero();
+    from_digits_into(digits, base, &mut value);
+    value
+}
+
+/// Like [`from_digits`], but stores the result in `out`
+///
+/// Small inputs reuse the allocation of `out`.
+pub(crate) fn from_digits_into(digits: &[u32], base: u32, out: &mut BigInt) {
+    let mut powers = Powers::new(base);
+    if digits.len() <= 2 * powers.chunk {
+        out.set_zero();
+        accumulate_naive(out, digits, powers.limb);
+    } else {
+        // Compute every power the recursion needs up front, so the halves
+        // can share them across threads
+        powers.get(powers.split_level(digits.len()));
+        *out = from_digits_dc(digits, &powers, available_threads());
+    }
+}
+
+/// Convert a BigInt to digits in `base`, most significant first
+///
+/// Leading zeros are never emitted; zero is returned as `[0]`.
+pub(crate) fn to_digits(mut value: BigInt, base: u32) -> Vec<u32> {
+    let mut digits = Vec::new();
+    to_digits_into(&mut value, base, &mut digits);
+    digits
+}
+
+/// Like [`to_digits`], but replaces the contents of `out` with the digits
+///
+/// `value` is consumed and left unspecified. Small values reuse the
+/// allocation of `out` and do not allocate.
+pub(crate) fn to_digits_into(value: &mut BigInt, base: u32, out: &mut Vec<u32>) {
+    out.clear();
+    if value.limb_len() <= 2 * DC_THRESHOLD_LIMBS {
+        extract_naive(value, LimbChunk::new(base), out);
+        return;
+    }
+
+    // Find the smallest level whose power exceeds the value
+    let mut powers = Powers::new(base);
+    let mut level = 0;
+    while powers.get(level).limb_len() <= value.limb_len()
+        && *powers.get(level) <= *value
+    {
+        level += 1;
+    }
+
+    out.resize(powers.chunk << level, 0);
+    let value = std::mem::take(value);
+    to_digits_dc(value, level, out, &powers, available_threads());
+    let leading = out.iter().take_while(|&&d| d == 0).count();
+    out.drain(..leading.min(out.len() - 1));
+}
+
+/// Quadratic conversion: one multiply-add pass per limb-sized chunk of digits
+pub(crate) fn from_digits_naive(digits: &[u32], base: u32) -> BigInt {
+    let mut big = BigInt::zero();
+    accumulate_naive(&mut big, digits, LimbChunk::new(base));
+    big
+}
+
+/// Quadratic conversion: one division pass per limb-sized chunk of digits
+#[cfg(any(test, feature = "bench"))]
+pub(crate) fn to_digits_naive(mut value: BigInt, base: u32) -> Vec<u32> {
+    let mut digits = Vec::new();
+    extract_naive(&mut value, LimbChunk::new(base), &mut digits);
+    digits
+}
+
+/// Append `digits` to `big`: `big = big * base^digits.len() + value(digits)`
+fn accumulate_naive(big: &mut BigInt, digits: &[u32], chunk: LimbChunk) {
+    for block in digits.chunks(chunk.digits) {
+        // The final block may be shorter
+        big.mul_small(chunk.power_of(block.len()));
+        big.add_small(chunk.value(block));
+    }
+}
+
+/// Push the digits of `value` onto the empty `digits`, most significant first
+///
+/// `value` is divided down to zero.
+fn extract_naive(value: &mut BigInt, chunk: LimbChunk, digits: &mut Vec<u32>) {
+    if value.is_zero() {
+        digits.push(0);
+        return;
+    }
+    let base = chunk.base;
+    // Collected least significant first
+    while !value.is_zero() {
+        let mut rem = value.div_mod_small(chunk.power);
+        if value.is_zero() {
+            // Most significant chunk: no zero padding
+            while rem != 0 {
+                digits.push(rem % base);
+                rem /= base;
+            }
+        } else {
+            // Inner chunk: always exactly `chunk.digits` digits
+            for _ in 0..chunk.digits {
+                digits.push(rem % base);
+                rem /= base;
+            }
+        }
+    }
+    digits.reverse();
+}
+
+/// Requires the powers up to `powers.split_level(digits.len())` to be computed.
+fn from_digits_dc(digits: &[u32], powers: &Powers, threads: usize) -> BigInt {
+    let n = digits.len();
+    if n <= powers.chunk {
+        return from_digits_naive(digits, powers.limb.base);
+    }
+    // Split off the largest low block of `chunk * 2^level` digits
+    let level = powers.split_level(n);
+    let (high, low) = digits.split_at(n - (powers.chunk << level));
+    let (high, low) = join(
+        threads,
+        n,
+        |t| from_digits_dc(high, powers, t),
+        |t| from_digits_dc(low, powers, t),
+    );
+    let mut value = &high * &powers.pows[level];
+    value += &low;
+    value
+}
+
+/// Write `value` into `out` as exactly `out.len()` digits, zero padded
+///
+/// Requires `out.len() == chunk << level` and `value < base^out.len()`.
+fn to_digits_dc(mut value: BigInt, level: usize, out: &mut [u32], powers: &Powers, threads: usize) {
+    if value.is_zero() {
+        return;
+    }
+    if level == 0 || value.limb_len() <= DC_THRESHOLD_LIMBS {
+        let LimbChunk { base, digits, power } = powers.limb;
+        let mut i = out.len();
+        while !value.is_zero() {
+            let mut rem = value.div_mod_small(power);
+            for _ in 0..digits {
+                if rem == 0 && value.is_zero() {
+                    break;
+                }
+                i -= 1;
+                out[i] = rem % base;
+                rem /= base;
+            }
+        }
+        return;
+    }
+    let (q, r) = value.div_rem(&powers.pows[level - 1]);
+    let n = out.len();
+    let (high, low) = out.split_at_mut(n / 2);
+    join(
+        threads,
+        n,
+        |t| to_digits_dc(q, level - 1, high, powers, t),
+        |t| to_digits_dc(r, level - 1, low, powers, t),
+    );
+}
+
+/// Lazy iterator over the digits of a [`BigInt`], least significant first
+///
+/// Created by [`BigInt::digits_le`]. Each step divides by the largest power
+/// of the base that fits in a limb and then yields that many digits, so the
+/// full conversion costs the same as [`BigInt::to_digits`] but stops early
+/// if the iterator is dropped. Zero yields a single `0`.
+#[derive(Clone, Debug)]
+pub struct Digits {
+    value: BigInt,
+    chunk: LimbChunk,
+    /// Digits of the current chunk not yet yielded
+    rem: u32,
+    /// Number of digits of `rem` still to yield
+    left: usize,
+    /// Whether anything was yielded yet, so zero yields one digit
+    started: bool,
+}
+
+impl Digits {
+    pub(crate) fn new(value: BigInt, base: u32) -> Self {
+        Digits {
+            value,
+            chunk: LimbChunk::new(base),
+            rem: 0,
+            left: 0,
+            started: false,
+        }
+    }
+}
+
+impl Iterator for Digits {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.left == 0 {
+            if self.value.is_zero() {
+                if self.started {
+                    return None;
+                }
+                self.started = true;
+                return Some(0);
+            }
+            self.started = true;
+            self.rem = self.value.div_mod_small(self.chunk.power);
+            self.left = if self.value.is_zero() {
+                // Most significant chunk: no zero padding
+                let mut count = 0;
+                let mut r = self.rem;
+                while r != 0 {
+                    count += 1;
+                    r /= self.chunk.base;
+                }
+                count
+            } else {
+                // Inner chunk: always exactly `chunk.digits` digits
+                self.chunk.digits
+            };
+        }
+        self.left -= 1;
+        let digit = self.rem % self.chunk.base;
+        self.rem /= self.chunk.base;
+        Some(digit)
+    }
+}
+
+impl std::iter::FusedIterator for Digits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::Rng;
+
+    /// One digit per pass, independent of the chunked implementation
+    fn reference_from_digits(digits: &[u32], base: u32) -> BigInt {
+        let mut big = BigInt::zero();
+        for &digit in digits {
+            big.mul_small(base);
+            big.add_small(digit);
+        }
+        big
+    }
+
+    #[test]
+    fn test_chunk_boundaries() {
+        for base in [2, 3, 7, 10, 16, 255, 256, 65535, 65536, 65537, u32::MAX] {
+            let k = LimbChunk::new(base).digits;
+            for len in [1, k - 1, k, k + 1, 2 * k, 2 * k + 1, 5 * k + 3] {
+                if len == 0 {
+                    continue;
+                }
+                // A one followed by zeros exercises the padding of inner chunks
+                let mut digits = vec![0; len];
+                digits[0] = 1;
+                let mut all_max = vec![base - 1; len];
+                for case in [&mut digits, &mut all_max] {
+                    let value = from_digits_naive(case, base);
+                    assert_eq!(value, reference_from_digits(case, base), "base {} len {}", base, len);
+                    assert_eq!(to_digits_naive(value, base), *case, "base {} len {}", base, len);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dc_matches_naive() {
+        let mut rng = Rng(0x853c49e6748fea9b);
+        for base in [2, 3, 10, 16, 36, 62, 1000, 65537, u32::MAX] {
+            for len in [1, 500, 3000, 9000] {
+                let digits = rng.digits(len, base);
+                let value = from_digits(&digits, base);
+                assert_eq!(value, reference_from_digits(&digits, base), "base {}", base);
+
+                let start = digits.iter().position(|&d| d != 0).unwrap_or(len - 1);
+                let formatted = to_digits(value.clone(), base);
+                assert_eq!(formatted, &digits[start..], "base {}", base);
+                assert_eq!(formatted, to_digits_naive(value, base), "base {}", base);
+            }
+        }
+    }
+
+    #[test]
+    fn test_native_fast_path_matches_naive() {
+        let base62 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        let converter = crate::Converter::new("0123456789", base62);
+        let inputs = [0, 1, u128::from(u64::MAX), u128::from(u64::MAX) + 1, u128::MAX]
+            .map(|value| value.to_string());
+        // One past u128::MAX falls back to BigInt
+        let above = "340282366920938463463374607431768211456".to_string();
+        for input in inputs.iter().chain([&above]) {
+            let digits: Vec<u32> = input.bytes().map(|b| u32::from(b - b'0')).collect();
+            let value = from_digits_naive(&digits, 10);
+            let expected = converter.dst_alphabet().render(&to_digits_naive(value, 62));
+            assert_eq!(converter.convert(input).unwrap(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_parallel_matches_serial() {
+        let mut rng = Rng(0x5851f42d4c957f2d);
+        let len = 2 * PARALLEL_THRESHOLD_DIGITS + 123;
+        for base in [10, 62] {
+            let digits = rng.digits(len, base);
+            let mut powers = Powers::new(base);
+            powers.get(powers.split_level(len));
+            let serial = from_digits_dc(&digits, &powers, 1);
+            let parallel = from_digits_dc(&digits, &powers, 8);
+            assert_eq!(parallel, serial, "base {}", base);
+
+            let start = digits.iter().position(|&d| d != 0).unwrap();
+            assert_eq!(to_digits(parallel, base), &digits[start..], "base {}", base);
+        }
+    }
+}
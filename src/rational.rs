@@ -0,0 +1,306 @@
+use crate::alphabet::Alphabet;
+use crate::big_int::BigInt;
+use crate::converter::Converter;
+use crate::error::{Error, Result};
+use crate::fraction::exponent;
+use crate::radix;
+
+impl Alphabet {
+    /// Formats the exact value of `numerator / denominator`, enclosing the
+    /// repeating digits of its fraction part in the repetend marks.
+    ///
+    /// The fraction digits are split into the shortest non-repeating part
+    /// followed by the shortest repetend, so 1/6 in decimal is `0.1(6)`.
+    /// Fractions that terminate in this base have no repetend, and integers
+    /// have no radix point.
+    ///
+    /// # Arguments
+    ///
+    /// * `numerator` - The numerator, which may be negative
+    /// * `denominator` - The denominator, which may be negative
+    /// * `max_fraction_digits` - The most fraction digits to compute,
+    ///   repeating ones included. The repetend of `n / d` can be up to
+    ///   `d - 1` digits long, so this bounds the work for large denominators.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ZeroDenominator`] if `denominator` is zero,
+    /// [`Error::FractionLimitExceeded`] if the value needs more than
+    /// `max_fraction_digits` fraction digits, or [`Error::NegativeValue`],
+    /// [`Error::FractionalValue`] or [`Error::RepeatingValue`] if the value
+    /// needs a sign, a radix point or repetend marks that are not configured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt, Error};
+    ///
+    /// let bin = Alphabet::new("01").unwrap()
+    ///     .with_radix_point('.').unwrap()
+    ///     .with_repetend_marks('(', ')').unwrap();
+    /// let tenth = (BigInt::from(1u8), BigInt::from(10u8));
+    /// assert_eq!(bin.format_rational(&tenth.0, &tenth.1, 64).unwrap(), "0.0(0011)");
+    /// assert_eq!(bin.format_rational(&BigInt::from(5u8), &BigInt::from(4u8), 64).unwrap(), "1.01");
+    /// assert_eq!(
+    ///     bin.format_rational(&tenth.0, &tenth.1, 4),
+    ///     Err(Error::FractionLimitExceeded { limit: 4 })
+    /// );
+    ///
+    /// let base12 = Alphabet::new("0123456789AB").unwrap().with_radix_point('.').unwrap();
+    /// assert_eq!(base12.format_rational(&BigInt::from(1u8), &BigInt::from(3u8), 64).unwrap(), "0.4");
+    /// assert_eq!(
+    ///     base12.format_rational(&BigInt::from(1u8), &BigInt::from(5u8), 64),
+    ///     Err(Error::RepeatingValue)
+    /// );
+    /// ```
+    pub fn format_rational(
+        &self,
+        numerator: &BigInt,
+        denominator: &BigInt,
+        max_fraction_digits: usize,
+    ) -> Result<String> {
+        if denominator.is_zero() {
+            return Err(Error::ZeroDenominator);
+        }
+        let negative = numerator.is_negative() != denominator.is_negative();
+        let gcd = numerator.gcd(denominator);
+        let numerator = numerator.abs() / &gcd;
+        let denominator = denominator.abs() / &gcd;
+        let base = self.base() as u32;
+        let (integer, mut rem) = numerator.div_rem(&denominator);
+
+        // Each factor the denominator shares with the base delays the
+        // repetend by one digit
+        let mut pre_len = 0;
+        let mut coprime = denominator.clone();
+        let big_base = BigInt::from(base);
+        loop {
+            let common = coprime.gcd(&big_base);
+            if common == BigInt::from(1u8) {
+                break;
+            }
+            coprime = &coprime / &common;
+            pre_len += 1;
+        }
+
+        // Long division; once past the non-repeating digits, the remainder
+        // returns to its first value after exactly one period
+        let next_digit = |rem: &mut BigInt| {
+            rem.mul_small(base);
+            let (digit, next) = rem.div_rem(&denominator);
+            *rem = next;
+            u32::try_from(&digit).expect("digit is less than the base")
+        };
+        let too_long = Error::FractionLimitExceeded {
+            limit: max_fraction_digits,
+        };
+        let mut fraction = Vec::new();
+        while fraction.len() < pre_len && !rem.is_zero() {
+            if fraction.len() == max_fraction_digits {
+                return Err(too_long);
+            }
+            fraction.push(next_digit(&mut rem));
+        }
+        let mut repetend = Vec::new();
+        if !rem.is_zero() {
+            let start = rem.clone();
+            loop {
+                if fraction.len() + repetend.len() == max_fraction_digits {
+                    return Err(too_long);
+                }
+                repetend.push(next_digit(&mut rem));
+                if rem == start {
+                    break;
+                }
+            }
+        }
+
+        let sign = self.sign_char(negative && !numerator.is_zero())?;
+        let point = match fraction.is_empty() && repetend.is_empty() {
+            true => None,
+            false => Some(self.radix_point().ok_or(Error::FractionalValue)?),
+        };
+        let marks = match repetend.is_empty() {
+            true => None,
+            false => Some(self.repetend_marks().ok_or(Error::RepeatingValue)?),
+        };
+
+        let mut out = String::new();
+        out.extend(sign);
+        self.render_into(&radix::to_digits(integer, base), &mut out);
+        out.extend(point);
+        self.render_into(&fraction, &mut out);
+        if let Some((open, close)) = marks {
+            out.push(open);
+            self.render_into(&repetend, &mut out);
+            out.push(close);
+        }
+        Ok(out)
+    }
+
+    /// Parses a number in the notation of [`Alphabet::format_rational`] into
+    /// its exact value.
+    ///
+    /// The input may have a sign, a radix point and, after the radix point,
+    /// repeating digits enclosed in the repetend marks. The repetend need not
+    /// be the shortest one, so `0.(33)` and `0.3(3)` both parse as 1/3.
+    ///
+    /// # Returns
+    ///
+    /// The numerator and denominator in lowest terms. The denominator is
+    /// positive and carries no sign.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the table or a misplaced symbol, such as a bare sign
+    /// or an unclosed, empty or trailing repetend, or
+    /// [`Error::FractionLimitExceeded`] if there are more than
+    /// [`MAX_FRACTION_DIGITS`](crate::MAX_FRACTION_DIGITS) fraction or
+    /// repetend digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt};
+    ///
+    /// let bin = Alphabet::new("01").unwrap()
+    ///     .with_signs('-', None).unwrap()
+    ///     .with_radix_point('.').unwrap()
+    ///     .with_repetend_marks('(', ')').unwrap();
+    /// let (numerator, denominator) = bin.parse_rational("-0.0(0011)").unwrap();
+    /// assert_eq!(numerator, BigInt::from(-1i32));
+    /// assert_eq!(denominator, BigInt::from(10u32));
+    /// ```
+    pub fn parse_rational(&self, input: &str) -> Result<(BigInt, BigInt)> {
+        let (negative, _, unsigned) = self.split_sign_strict(input)?;
+        let (integer, fraction) = match self.radix_point().and_then(|p| unsigned.split_once(p)) {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (unsigned, ""),
+        };
+        let (fraction, repetend) = match self.repetend_marks() {
+            Some((open, close)) => match fraction.split_once(open) {
+                Some((fraction, rest)) => {
+                    let open_offset = offset_in(input, rest) - open.len_utf8();
+                    let Some((repetend, trailing)) = rest.split_once(close) else {
+                        return Err(Error::invalid_character(input, open_offset));
+                    };
+                    if repetend.is_empty() {
+                        return Err(Error::invalid_character(input, offset_in(input, rest)));
+                    }
+                    if !trailing.is_empty() {
+                        return Err(Error::invalid_character(input, offset_in(input, trailing)));
+                    }
+                    (fraction, Some(repetend))
+                }
+                None => (fraction, None),
+            },
+            None => (fraction, None),
+        };
+
+        let base = self.base() as u32;
+        let mut digits = self.decode_part(input, integer)?;
+        let fraction_digits = self.decode_part(input, fraction)?;
+        digits.extend(&fraction_digits);
+        let mut numerator = radix::from_digits(&digits, base);
+        let mut denominator = BigInt::from(base).pow(exponent(fraction_digits.len())?);
+
+        // x.y(z) = (xy + z / (base^|z| - 1)) / base^|y|
+        if let Some(repetend) = repetend {
+            let repetend_digits = self.decode_part(input, repetend)?;
+            let period =
+                BigInt::from(base).pow(exponent(repetend_digits.len())?) - BigInt::from(1u8);
+            numerator = numerator * &period + radix::from_digits(&repetend_digits, base);
+            denominator *= period;
+        }
+
+        let gcd = numerator.gcd(&denominator);
+        let numerator = numerator / &gcd;
+        let numerator = if negative { -numerator } else { numerator };
+        Ok((numerator, denominator / gcd))
+    }
+
+    /// Decodes the digits of `part`, a subslice of `input`, reporting errors
+    /// relative to `input`
+    fn decode_part(&self, input: &str, part: &str) -> Result<Vec<u32>> {
+        self.decode_digits(part)
+            .map_err(|e| e.after_prefix(&input[..offset_in(input, part)]))
+    }
+}
+
+/// Byte offset of `part` within `input`, which it must be a subslice of
+fn offset_in(input: &str, part: &str) -> usize {
+    part.as_ptr() as usize - input.as_ptr() as usize
+}
+
+impl Converter {
+    /// Converts a number exactly, marking the repeating digits of its
+    /// fraction part in the destination base.
+    ///
+    /// The input uses the notation of [`Alphabet::parse_rational`], so it can
+    /// be an integer, a terminating fraction or a repeating one. The output
+    /// is formatted like [`Alphabet::format_rational`].
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The number to convert, e.g. `"0.(3)"`
+    /// * `max_fraction_digits` - The most fraction digits of the output,
+    ///   repeating ones included; see [`Alphabet::format_rational`]
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Alphabet::parse_rational`] for the source
+    /// table and of [`Alphabet::format_rational`] for the destination table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{base, Converter, Error};
+    ///
+    /// let converter = Converter::new(base::DEC, base::BIN)
+    ///     .with_radix_point('.').unwrap()
+    ///     .with_repetend_marks('(', ')').unwrap();
+    /// assert_eq!(converter.convert_rational("0.1", 64).unwrap(), "0.0(0011)");
+    /// assert_eq!(converter.inverse().convert_rational("0.0(0011)", 64).unwrap(), "0.1");
+    /// assert_eq!(converter.convert_rational("0.(3)", 64).unwrap(), "0.(01)");
+    /// assert_eq!(
+    ///     converter.convert_rational("0.(0000000001)", 64),
+    ///     Err(Error::FractionLimitExceeded { limit: 64 })
+    /// );
+    /// ```
+    pub fn convert_rational(&self, input: &str, max_fraction_digits: usize) -> Result<String> {
+        let (numerator, denominator) = self.src_alphabet().parse_rational(input)?;
+        self.dst_alphabet()
+            .format_rational(&numerator, &denominator, max_fraction_digits)
+    }
+
+    /// Formats the exact value of `numerator / denominator` using the
+    /// destination table.
+    ///
+    /// See [`Alphabet::format_rational`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Alphabet::format_rational`].
+    pub fn format_rational(
+        &self,
+        numerator: &BigInt,
+        denominator: &BigInt,
+        max_fraction_digits: usize,
+    ) -> Result<String> {
+        self.dst_alphabet()
+            .format_rational(numerator, denominator, max_fraction_digits)
+    }
+
+    /// Parses a possibly repeating fraction in the source table into its
+    /// numerator and denominator in lowest terms.
+    ///
+    /// See [`Alphabet::parse_rational`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Alphabet::parse_rational`].
+    pub fn parse_rational(&self, input: &str) -> Result<(BigInt, BigInt)> {
+        self.src_alphabet().parse_rational(input)
+    }
+}
@@ -0,0 +1,143 @@
+use crate::alphabet::Alphabet;
+use crate::big_int::BigInt;
+use crate::error::{Error, Result};
+use crate::fraction::MAX_FRACTION_DIGITS;
+use crate::radix;
+
+impl Alphabet {
+    /// Encodes a signed value as a fixed-width two's complement string.
+    ///
+    /// The output always has exactly `width` digits. Non-negative values are
+    /// padded with zero characters, and a negative value `v` is encoded as
+    /// `base^width + v`, so `-1` is all maximum digits. In a base other than
+    /// two this is the radix complement, e.g. ten's complement in decimal.
+    /// Values with `2 * v >= base^width` are treated as negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to encode
+    /// * `width` - The number of digits of the output
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WidthExceeded`] if `value` is outside the range
+    /// representable in `width` digits, `-base^width / 2` up to
+    /// `base^width / 2`, exclusive for non-negative values, or if `width` is
+    /// more than [`MAX_FRACTION_DIGITS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt, Error};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap();
+    /// assert_eq!(hex.encode_twos_complement(&BigInt::from(-1i32), 2).unwrap(), "ff");
+    /// assert_eq!(hex.encode_twos_complement(&BigInt::from(-128i32), 2).unwrap(), "80");
+    /// assert_eq!(hex.encode_twos_complement(&BigInt::from(127i32), 2).unwrap(), "7f");
+    /// assert_eq!(
+    ///     hex.encode_twos_complement(&BigInt::from(128i32), 2),
+    ///     Err(Error::WidthExceeded { width: 2 })
+    /// );
+    /// ```
+    pub fn encode_twos_complement(&self, value: &BigInt, width: usize) -> Result<String> {
+        let modulus = self.modulus(width)?;
+        let mut twice = value.abs();
+        twice.mul_small(2);
+        let fits = if value.is_negative() {
+            twice <= modulus
+        } else {
+            twice < modulus
+        };
+        if !fits {
+            return Err(Error::WidthExceeded { width });
+        }
+
+        let encoded = if value.is_negative() {
+            modulus + value
+        } else {
+            value.clone()
+        };
+        let digits = radix::to_digits(encoded, self.base() as u32);
+        // Zero is a single digit, which is too many for a width of zero
+        let padding = width.saturating_sub(digits.len());
+        let mut out: String = std::iter::repeat_n(self.zero_char(), padding).collect();
+        if width > 0 {
+            self.render_into(&digits, &mut out);
+        }
+        Ok(out)
+    }
+
+    /// Decodes a fixed-width two's complement string produced by
+    /// [`Alphabet::encode_twos_complement`].
+    ///
+    /// The width is the number of characters of `input`, so leading zero
+    /// characters are significant: `"ff"` is `-1`, while `"0ff"` is `255`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the table, including sign characters, or
+    /// [`Error::WidthExceeded`] if it has more than [`MAX_FRACTION_DIGITS`]
+    /// characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap();
+    /// assert_eq!(hex.decode_twos_complement("ff").unwrap(), BigInt::from(-1i32));
+    /// assert_eq!(hex.decode_twos_complement("0ff").unwrap(), BigInt::from(255i32));
+    /// ```
+    pub fn decode_twos_complement(&self, input: &str) -> Result<BigInt> {
+        let value = self.parse_unsigned(input)?;
+        let modulus = self.modulus(input.chars().count())?;
+        let mut twice = value.clone();
+        twice.mul_small(2);
+        Ok(if twice >= modulus {
+            value - modulus
+        } else {
+            value
+        })
+    }
+
+    /// Encodes a signed value using zigzag encoding; see [`BigInt::to_zigzag`].
+    ///
+    /// Unlike two's complement, zigzag needs no fixed width: values of small
+    /// magnitude have short encodings whatever their sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anybase::{Alphabet, BigInt};
+    ///
+    /// let hex = Alphabet::new("0123456789abcdef").unwrap();
+    /// assert_eq!(hex.encode_zigzag(&BigInt::from(-1i32)), "1");
+    /// assert_eq!(hex.encode_zigzag(&BigInt::from(-128i32)), "ff");
+    /// assert_eq!(hex.decode_zigzag("ff").unwrap(), BigInt::from(-128i32));
+    /// ```
+    pub fn encode_zigzag(&self, value: &BigInt) -> String {
+        self.format_unsigned(value.to_zigzag())
+    }
+
+    /// Decodes a string produced by [`Alphabet::encode_zigzag`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCharacter`] if `input` contains a character
+    /// that is not in the table, including sign characters.
+    pub fn decode_zigzag(&self, input: &str) -> Result<BigInt> {
+        BigInt::from_zigzag(&self.parse_unsigned(input)?)
+    }
+
+    /// `base^width`, the number of values representable in `width` digits
+    ///
+    /// Widths share the bound on fraction digits, which keeps `base^width`
+    /// as small.
+    fn modulus(&self, width: usize) -> Result<BigInt> {
+        if width > MAX_FRACTION_DIGITS {
+            return Err(Error::WidthExceeded { width });
+        }
+        Ok(BigInt::from(self.base()).pow(width as u32))
+    }
+}
@@ -0,0 +1,229 @@
+//! Conversion strategies chosen when a `Converter` is built
+//!
+//! The general strategy goes through a `BigInt`. When the two bases are
+//! related, conversion needs no big-number arithmetic and runs in linear time.
+
+/// How a `Converter` turns source digits into destination digits
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Strategy {
+    /// Parse into a `BigInt` and format it again
+    General,
+    /// Both bases are equal: substitute each character
+    Transliterate,
+    /// Both bases are powers of two: regroup the bits of the digits
+    BitRegroup {
+        /// Bits per source digit
+        src_bits: u32,
+        /// Bits per destination digit
+        dst_bits: u32,
+    },
+    /// `dst_base == src_base^digits`: each group of source digits maps to one destination digit
+    Group {
+        /// Source digits per destination digit
+        digits: usize,
+    },
+    /// `src_base == dst_base^digits`: each source digit maps to a group of destination digits
+    Split {
+        /// Destination digits per source digit
+        digits: usize,
+    },
+}
+
+impl Strategy {
+    /// Choose the fastest strategy that is exact for the given bases
+    pub(crate) fn select(src_base: usize, dst_base: usize) -> Self {
+        if src_base == dst_base {
+            return Strategy::Transliterate;
+        }
+        if src_base.is_power_of_two() && dst_base.is_power_of_two() {
+            return Strategy::BitRegroup {
+                src_bits: src_base.trailing_zeros(),
+                dst_bits: dst_base.trailing_zeros(),
+            };
+        }
+        if let Some(digits) = exact_power(src_base, dst_base) {
+            return Strategy::Group { digits };
+        }
+        if let Some(digits) = exact_power(dst_base, src_base) {
+            return Strategy::Split { digits };
+        }
+        Strategy::General
+    }
+}
+
+/// Returns `k >= 2` with `base^k == power`, if there is one
+fn exact_power(base: usize, power: usize) -> Option<usize> {
+    let (base, power) = (base as u64, power as u64);
+    let mut p = base;
+    let mut k = 1;
+    while p < power {
+        p *= base;
+        k += 1;
+    }
+    (k >= 2 && p == power).then_some(k)
+}
+
+/// Regroup digits of `src_bits` bits into digits of `dst_bits` bits
+///
+/// Digits are given most significant first and replace the contents of
+/// `out` in the same order. Leading zeros are stripped from the output; zero
+/// is written as `[0]`.
+pub(crate) fn regroup_bits(digits: &[u32], src_bits: u32, dst_bits: u32, out: &mut Vec<u32>) {
+    let mask = (1u64 << dst_bits) - 1;
+    // Collected least significant first; `acc` never holds more than
+    // `dst_bits + src_bits` < 64 bits
+    out.clear();
+    out.reserve((digits.len() * src_bits as usize).div_ceil(dst_bits as usize));
+    let mut acc: u64 = 0;
+    let mut acc_bits = 0;
+    for &digit in digits.iter().rev() {
+        acc |= u64::from(digit) << acc_bits;
+        acc_bits += src_bits;
+        while acc_bits >= dst_bits {
+            out.push((acc & mask) as u32);
+            acc >>= dst_bits;
+            acc_bits -= dst_bits;
+        }
+    }
+    if acc_bits > 0 {
+        out.push(acc as u32);
+    }
+    strip_leading_zeros(out);
+    out.reverse();
+}
+
+/// Combine each group of `group` digits into one digit of base `base^group`
+///
+/// Digits are given most significant first and replace the contents of
+/// `out` in the same order. Leading zeros are stripped from the output; zero
+/// is written as `[0]`.
+pub(crate) fn group_digits(digits: &[u32], base: u32, group: usize, out: &mut Vec<u32>) {
+    // Collected least significant first
+    out.clear();
+    out.extend(digits.rchunks(group).map(|chunk| {
+        // Cannot overflow: the value is below base^group, a valid base
+        chunk.iter().fold(0, |acc, &d| acc * base + d)
+    }));
+    strip_leading_zeros(out);
+    out.reverse();
+}
+
+/// Split each digit of base `base^group` into `group` digits of base `base`
+///
+/// Digits are given most significant first and replace the contents of
+/// `out` in the same order. Leading zeros are stripped from the output; zero
+/// is written as `[0]`.
+pub(crate) fn split_digits(digits: &[u32], base: u32, group: usize, out: &mut Vec<u32>) {
+    // Collected least significant first
+    out.clear();
+    out.reserve(digits.len() * group);
+    for &digit in digits.iter().rev() {
+        let mut d = digit;
+        for _ in 0..group {
+            out.push(d % base);
+            d /= base;
+        }
+    }
+    strip_leading_zeros(out);
+    out.reverse();
+}
+
+/// Strip leading zeros from digits given most significant first
+///
+/// Zero is returned as `[0]`.
+pub(crate) fn strip_leading(digits: &[u32]) -> &[u32] {
+    let start = digits
+        .iter()
+        .position(|&d| d != 0)
+        .unwrap_or(digits.len().saturating_sub(1));
+    if digits.is_empty() { &[0] } else { &digits[start..] }
+}
+
+/// Remove most significant zeros from digits stored least significant
+/// first, keeping a single zero for the value zero
+fn strip_leading_zeros(digits: &mut Vec<u32>) {
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        digits.push(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::radix;
+    use crate::test_rng::Rng;
+
+    #[test]
+    fn test_select() {
+        assert_eq!(Strategy::select(10, 1000), Strategy::Group { digits: 3 });
+        assert_eq!(Strategy::select(36, 6), Strategy::Split { digits: 2 });
+        assert_eq!(Strategy::select(3, 27), Strategy::Group { digits: 3 });
+        assert_eq!(Strategy::select(10, 16), Strategy::General);
+        assert_eq!(Strategy::select(10, 10), Strategy::Transliterate);
+        assert_eq!(Strategy::select(16, 16), Strategy::Transliterate);
+        assert_eq!(
+            Strategy::select(2, 8),
+            Strategy::BitRegroup {
+                src_bits: 1,
+                dst_bits: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_group_and_split_match_general() {
+        let mut rng = Rng(0x2545f4914f6cdd1d);
+        for (base, group) in [(3, 3), (6, 2), (10, 3), (10, 9), (7, 2), (65535, 2)] {
+            let big_base = u32::pow(base, group as u32);
+            for len in [0, 1, 2, 3, 4, 10, 99] {
+                let mut small_digits = rng.digits(len, base);
+                if len > 1 {
+                    small_digits[0] = 0;
+                }
+                let value = radix::from_digits(&small_digits, base);
+                let expected = radix::to_digits(value, big_base);
+                let mut grouped = Vec::new();
+                group_digits(&small_digits, base, group, &mut grouped);
+                assert_eq!(grouped, expected, "base {}^{}, {} digits", base, group, len);
+
+                let value = radix::from_digits(&grouped, big_base);
+                let expected = radix::to_digits(value, base);
+                let mut split = vec![7; 3];
+                split_digits(&grouped, base, group, &mut split);
+                assert_eq!(split, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_regroup_matches_general() {
+        let mut rng = Rng(0x9e3779b97f4a7c15);
+        for src_bits in 1..=8 {
+            for dst_bits in 1..=8 {
+                for len in [0, 1, 2, 7, 64, 301] {
+                    let mut digits = rng.digits(len, 1 << src_bits);
+                    // Leading zeros must be stripped like the general path does
+                    if len > 2 {
+                        digits[0] = 0;
+                        digits[1] = 0;
+                    }
+                    let value = radix::from_digits(&digits, 1 << src_bits);
+                    let expected = radix::to_digits(value, 1 << dst_bits);
+                    let mut out = Vec::new();
+                    regroup_bits(&digits, src_bits, dst_bits, &mut out);
+                    assert_eq!(
+                        out,
+                        expected,
+                        "{} -> {} bits, {} digits",
+                        src_bits,
+                        dst_bits,
+                        len
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Checks that the buffer-reusing conversion APIs do not allocate once warm.
+//!
+//! This file holds a single test so no other thread allocates while counting.
+
+use anybase::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+#[test]
+fn test_steady_state_does_not_allocate() {
+    let base62 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let converters = [
+        // Native fast path and BigInt fallback
+        Converter::new(base::DEC, base62),
+        // Linear-time strategies
+        Converter::new(base::HEX, base::BIN),
+        Converter::new(base::DEC, "0123456789"),
+        Converter::new("012", "012345678"),
+        Converter::new("012345678", "012"),
+    ];
+    // Valid in every source table; long enough to overflow u64 and u128
+    let inputs = ["10110".to_string(), "1".repeat(30), "1".repeat(50), "1".repeat(200)];
+
+    let mut out = String::new();
+    let mut buf = [0u8; 1024];
+    for converter in &converters {
+        for input in &inputs {
+            // Warm up the output and the scratch buffers
+            converter.convert_into(input, &mut out).unwrap();
+            out.clear();
+
+            let count = allocations_during(|| {
+                converter.convert_into(input, &mut out).unwrap();
+                out.clear();
+                converter.write_to(input, &mut out).unwrap();
+                out.clear();
+                converter.convert_to_slice(input, &mut buf).unwrap();
+            });
+            assert_eq!(count, 0, "{:?} converting {}", converter, input);
+        }
+    }
+
+    // Sanity check that the counter works
+    assert!(allocations_during(|| write!(out, "{}", "x".repeat(4096)).unwrap()) > 0);
+}
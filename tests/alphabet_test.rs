@@ -0,0 +1,81 @@
+use anybase::*;
+use std::sync::Arc;
+
+#[test]
+fn test_alphabet_validation() {
+    assert_eq!(
+        Alphabet::new("").err(),
+        Some(Error::EmptyTable {
+            table: Table::Alphabet
+        })
+    );
+    assert_eq!(
+        Alphabet::new("abca").err(),
+        Some(Error::DuplicateCharacter {
+            table: Table::Alphabet,
+            ch: 'a',
+            first: 0,
+            second: 3,
+        })
+    );
+}
+
+#[test]
+fn test_alphabet_digits() {
+    let alphabet = Alphabet::new("你好世界").unwrap();
+    assert_eq!(alphabet.base(), 4);
+    assert_eq!(alphabet.decode_digits("界好").unwrap(), vec![3, 1]);
+    assert_eq!(alphabet.encode_digits(&[2, 0]).unwrap(), "世你");
+    assert_eq!(
+        alphabet.encode_digits(&[1, 4]),
+        Err(Error::InvalidDigit {
+            digit: 4,
+            index: 1,
+            base: 4,
+        })
+    );
+}
+
+#[test]
+fn test_shared_alphabets() {
+    let bin = Arc::new(Alphabet::new(base::BIN).unwrap());
+    let dec = Arc::new(Alphabet::new(base::DEC).unwrap());
+    let converter = Converter::from_alphabets(bin.clone(), dec.clone());
+    let inverse = converter.inverse();
+
+    assert!(Arc::ptr_eq(inverse.src_alphabet(), &dec));
+    assert!(Arc::ptr_eq(inverse.dst_alphabet(), &bin));
+    assert_eq!(converter.convert("1010").unwrap(), "10");
+    assert_eq!(inverse.convert("10").unwrap(), "1010");
+}
+
+#[test]
+fn test_ascii_lookup_rejects_non_ascii() {
+    let dec = Alphabet::new(base::DEC).unwrap();
+    assert_eq!(dec.digit('é'), None);
+    assert_eq!(dec.digit('\u{ff}'), None);
+    assert_eq!(
+        dec.decode_digits("12é3"),
+        Err(Error::InvalidCharacter {
+            ch: 'é',
+            byte_offset: 2,
+            char_index: 2,
+        })
+    );
+}
+
+#[test]
+fn test_large_unicode_alphabet() {
+    // Mixed ASCII and CJK characters use the sorted lookup
+    let table: String = ('0'..='9').chain('\u{4e00}'..'\u{51e8}').collect();
+    let alphabet = Alphabet::new(table).unwrap();
+    assert_eq!(alphabet.base(), 1010);
+    for digit in [0, 9, 10, 500, 1009] {
+        let ch = alphabet.char(digit).unwrap();
+        assert_eq!(alphabet.digit(ch), Some(digit));
+    }
+    assert_eq!(alphabet.digit('a'), None);
+    let digits = vec![1009, 0, 9, 10];
+    let encoded = alphabet.encode_digits(&digits).unwrap();
+    assert_eq!(alphabet.decode_digits(&encoded).unwrap(), digits);
+}
@@ -0,0 +1,60 @@
+use anybase::*;
+
+#[test]
+fn test_batch_matches_convert() {
+    let converter = Converter::new(
+        base::DEC,
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    );
+    let long = "9".repeat(100);
+    let inputs = ["0", "", "12a", "18446744073709551615", &long, "x", "42"];
+    let batch = converter.convert_batch(inputs);
+
+    assert_eq!(batch.len(), inputs.len());
+    assert_eq!(batch.offsets().len(), inputs.len() + 1);
+    for (i, input) in inputs.iter().enumerate() {
+        let result = batch
+            .get(i)
+            .unwrap()
+            .map(str::to_string)
+            .map_err(Error::clone);
+        assert_eq!(result, converter.convert(input));
+    }
+    assert_eq!(batch.get(inputs.len()), None);
+    assert_eq!(
+        batch.errors().iter().map(|&(i, _)| i).collect::<Vec<_>>(),
+        [2, 5]
+    );
+    assert!(!batch.is_ok());
+
+    let empty = converter.convert_batch(Vec::<String>::new());
+    assert!(empty.is_empty());
+    assert!(empty.is_ok());
+    assert_eq!(empty.offsets(), &[0]);
+}
+
+#[test]
+fn test_parallel_batch_matches_serial() {
+    let converter = Converter::new(base::DEC, "零一二三四五六七八九十");
+    let inputs: Vec<String> = (0..50_000u64)
+        .map(|n| {
+            if n % 997 == 0 {
+                format!("{}?", n)
+            } else {
+                (n * n * n).to_string()
+            }
+        })
+        .collect();
+
+    let serial = converter.convert_batch(&inputs);
+    let parallel = converter.convert_batch_parallel(&inputs);
+    assert_eq!(parallel, serial);
+    assert_eq!(parallel.errors().len(), 51);
+    assert_eq!(parallel.get(3), Some(Ok("二五")));
+
+    // Small batches stay on the calling thread and give the same result
+    assert_eq!(
+        converter.convert_batch_parallel(&inputs[..10]),
+        converter.convert_batch(&inputs[..10])
+    );
+}
@@ -0,0 +1,152 @@
+use anybase::*;
+
+mod common;
+use common::rng::Rng;
+
+#[test]
+fn test_operators_match_u128() {
+    let mut rng = Rng(0x2545f4914f6cdd1d);
+    for _ in 0..2000 {
+        // Up to 96 and 32 bits, so no operation below overflows a u128
+        let x = u128::from(rng.next() >> (rng.next() % 64)) << (rng.next() % 33);
+        let y = u128::from(rng.next() >> (32 + rng.next() % 32)).max(1);
+        let (a, b) = (BigInt::from(x), BigInt::from(y));
+
+        assert_eq!(&a + &b, BigInt::from(x + y));
+        assert_eq!(a.clone() * b.clone(), BigInt::from(x * y));
+        assert_eq!(&a / &b, BigInt::from(x / y));
+        assert_eq!(a.clone() % &b, BigInt::from(x % y));
+        assert_eq!(&a - &b, BigInt::from(x as i128 - y as i128));
+        assert_eq!(a.cmp(&b), x.cmp(&y));
+        assert_eq!(a.bits(), u64::from(128 - x.leading_zeros()));
+
+        let mut c = a.clone();
+        c += &b;
+        c *= BigInt::from(3u8);
+        c -= &b;
+        c /= &b;
+        c %= BigInt::from(1_000_000_007u64);
+        assert_eq!(u128::try_from(&c).unwrap(), ((x + y) * 3 - y) / y % 1_000_000_007);
+    }
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(u8::try_from(BigInt::from(255u32)), Ok(255));
+    assert_eq!(u8::try_from(BigInt::from(256u32)), Err(Error::Overflow { ty: "u8" }));
+    assert_eq!(u128::try_from(BigInt::from(u128::MAX)), Ok(u128::MAX));
+    let too_big = BigInt::from(u128::MAX) + BigInt::from(1u8);
+    assert_eq!(u128::try_from(&too_big), Err(Error::Overflow { ty: "u128" }));
+    assert_eq!(usize::try_from(BigInt::default()), Ok(0));
+
+    let big: BigInt = "340282366920938463463374607431768211456".parse().unwrap();
+    assert_eq!(big, too_big);
+    assert_eq!(big.to_string(), "340282366920938463463374607431768211456");
+    assert_eq!(format!("{:>5}", BigInt::from(42u8)), "   42");
+    assert_eq!(format!("{:?}", BigInt::from(42u8)), "BigInt(42)");
+    assert_eq!(
+        "12a".parse::<BigInt>(),
+        Err(Error::InvalidCharacter {
+            ch: 'a',
+            byte_offset: 2,
+            char_index: 2,
+        })
+    );
+    assert_eq!("".parse::<BigInt>(), Err(Error::EmptyInput));
+}
+
+#[test]
+fn test_parse_and_format_with_arithmetic() {
+    let converter = Converter::new(base::DEC, "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+    let input = "9".repeat(500);
+    let value = converter.parse(&input).unwrap();
+    assert_eq!(converter.format(&value).unwrap(), converter.convert(&input).unwrap());
+
+    // (10^500 - 1) + 1 == 10^500
+    let power = BigInt::from(10u32).pow(500);
+    assert_eq!(&value + BigInt::from(1u8), power);
+    assert_eq!(converter.inverse().parse(&converter.format(&power).unwrap()).unwrap(), power);
+    assert_eq!(converter.src_alphabet().format(&power).unwrap(), format!("1{}", "0".repeat(500)));
+
+    assert_eq!(value.checked_div(&BigInt::zero()), None);
+    assert_eq!(value.checked_rem(&BigInt::zero()), None);
+    assert_eq!(power.checked_rem(&value), Some(BigInt::from(1u8)));
+}
+
+#[test]
+fn test_signed_operators_match_i128() {
+    let mut rng = Rng(0x9e3779b97f4a7c15);
+    for _ in 0..2000 {
+        // Up to 80 and 40 bits with random signs, so nothing below overflows an i128
+        let x = i128::from(rng.next() >> (rng.next() % 64)) << (rng.next() % 17);
+        let x = if rng.next().is_multiple_of(2) { -x } else { x };
+        let y = i128::from(rng.next() >> (24 + rng.next() % 40)).max(1);
+        let y = if rng.next().is_multiple_of(2) { -y } else { y };
+        let (a, b) = (BigInt::from(x), BigInt::from(y));
+
+        assert_eq!(&a + &b, BigInt::from(x + y));
+        assert_eq!(&a - &b, BigInt::from(x - y));
+        assert_eq!(&b - &a, BigInt::from(y - x));
+        assert_eq!(&a * &b, BigInt::from(x * y));
+        assert_eq!(&a / &b, BigInt::from(x / y));
+        assert_eq!(&a % &b, BigInt::from(x % y));
+        assert_eq!(-&a, BigInt::from(-x));
+        assert_eq!(a.cmp(&b), x.cmp(&y));
+        assert_eq!(a.is_negative(), x < 0);
+        assert_eq!(a.abs(), BigInt::from(x.unsigned_abs()));
+        assert_eq!(i128::try_from(&a), Ok(x));
+        assert_eq!(a.to_string(), x.to_string());
+        assert_eq!(x.to_string().parse::<BigInt>(), Ok(a.clone()));
+        assert_eq!(BigInt::from_zigzag(&a.to_zigzag()), Ok(a));
+    }
+}
+
+#[test]
+fn test_signed_conversions() {
+    for v in [i64::MIN, -1, 0, 1, i64::MAX] {
+        assert_eq!(i64::try_from(BigInt::from(v)), Ok(v));
+    }
+    assert_eq!(i8::try_from(BigInt::from(-128i32)), Ok(-128));
+    assert_eq!(i8::try_from(BigInt::from(-129i32)), Err(Error::Overflow { ty: "i8" }));
+    assert_eq!(i8::try_from(BigInt::from(128i32)), Err(Error::Overflow { ty: "i8" }));
+    assert_eq!(i128::try_from(BigInt::from(i128::MIN)), Ok(i128::MIN));
+    assert_eq!(u32::try_from(BigInt::from(-1i32)), Err(Error::Overflow { ty: "u32" }));
+
+    // Zero has no sign
+    let negative_zero = -BigInt::zero();
+    assert!(!negative_zero.is_negative());
+    assert_eq!(negative_zero, BigInt::zero());
+    assert_eq!(BigInt::from(5i32) - BigInt::from(5i32), BigInt::zero());
+    assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::zero()));
+    assert_eq!((BigInt::from(-3i32) * BigInt::zero()).to_string(), "0");
+    assert_eq!(BigInt::from(-3i32) / BigInt::from(4u32), BigInt::zero());
+    assert!(!(BigInt::from(-3i32) / BigInt::from(4u32)).is_negative());
+
+    assert_eq!(format!("{:>5}", BigInt::from(-42i8)), "  -42");
+    assert_eq!(format!("{:+}", BigInt::from(42u8)), "+42");
+    assert_eq!(format!("{:?}", BigInt::from(-42i8)), "BigInt(-42)");
+    assert_eq!("+42".parse::<BigInt>(), Ok(BigInt::from(42u8)));
+    assert_eq!(
+        "-4-2".parse::<BigInt>(),
+        Err(Error::InvalidCharacter { ch: '-', byte_offset: 2, char_index: 2 })
+    );
+
+    let mut value = BigInt::from(-10i32);
+    value.add_small(15);
+    assert_eq!(value, BigInt::from(5u8));
+    let mut value = BigInt::from(-17i32);
+    assert_eq!(value.div_mod_small(5), 2);
+    assert_eq!(value, BigInt::from(-3i32));
+
+    // 0, -1, 1, -2, 2, ... map to 0, 1, 2, 3, 4, ...
+    for (v, z) in [(0i32, 0u32), (-1, 1), (1, 2), (-2, 3), (2, 4)] {
+        assert_eq!(BigInt::from(v).to_zigzag(), BigInt::from(z));
+    }
+    assert_eq!(BigInt::from_zigzag(&BigInt::from(-1i32)), Err(Error::NegativeValue));
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_div_by_zero_panics() {
+    let _ = BigInt::from(1u8) / BigInt::zero();
+}
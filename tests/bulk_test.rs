@@ -1,3 +1,6 @@
+// The failure messages keep the underlying error, which `expect` prints
+#![allow(clippy::expect_fun_call)]
+
 use anybase::*;
 
 #[test]
@@ -42,7 +45,7 @@ fn test_bidirectional_conversion() {
         {
             input.trim_start_matches(ch)
         } else {
-            &input
+            input
         };
 
         assert_eq!(
@@ -0,0 +1,57 @@
+use anybase::*;
+
+const BASE58: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+#[test]
+fn test_base58_vectors() {
+    let alphabet = Alphabet::new(BASE58).unwrap();
+    let cases: [(&[u8], &str); 6] = [
+        (b"", ""),
+        (&[0], "1"),
+        (&[0, 0, 0], "111"),
+        (&[0x61], "2g"),
+        (&[0x00, 0x00, 0x28, 0x7f, 0xb4, 0xcd], "11233QC4"),
+        (b"hello world", "StV1DL6CwTryKyV"),
+    ];
+    for (bytes, encoded) in cases {
+        assert_eq!(alphabet.encode_bytes(bytes), encoded);
+        assert_eq!(alphabet.decode_bytes(encoded).unwrap(), bytes);
+    }
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    let alphabet = Alphabet::new("你好世界").unwrap();
+    let payloads: [&[u8]; 4] = [
+        &[0, 0, 0, 1, 2, 3],
+        &[255; 33],
+        &[0, 255, 0, 0],
+        &[1, 0, 0, 0, 0],
+    ];
+    for payload in payloads {
+        for order in [ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+            let encoded = alphabet.encode_bytes_with_order(payload, order);
+            let decoded = alphabet.decode_bytes_with_order(&encoded, order).unwrap();
+            assert_eq!(decoded, payload);
+        }
+    }
+}
+
+#[test]
+fn test_little_endian_bytes() {
+    let alphabet = Alphabet::new(base::HEX).unwrap();
+    let le = alphabet.encode_bytes_with_order(&[0x01, 0x02, 0x00], ByteOrder::LittleEndian);
+    assert_eq!(le, "0201");
+    assert_eq!(
+        alphabet.decode_bytes_with_order("0201", ByteOrder::LittleEndian).unwrap(),
+        [0x01, 0x02, 0x00]
+    );
+    assert_eq!(
+        alphabet.decode_bytes("00x"),
+        Err(Error::InvalidCharacter {
+            ch: 'x',
+            byte_offset: 2,
+            char_index: 2,
+        })
+    );
+}
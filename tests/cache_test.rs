@@ -0,0 +1,69 @@
+//! The cache is global, so this file holds a single test.
+
+use anybase::*;
+use std::thread;
+
+#[test]
+fn test_convert_base_cache() {
+    let base36 = "0123456789abcdefghijklmnopqrstuvwxyz";
+    let base62 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    assert_eq!(cache::capacity(), cache::DEFAULT_CAPACITY);
+
+    // Presets never occupy the cache
+    assert_eq!(convert_base("255", base::DEC, base::HEX).unwrap(), "ff");
+    assert_eq!(cache::len(), 0);
+
+    assert_eq!(convert_base("zz", base36, base::DEC).unwrap(), "1295");
+    assert_eq!(convert_base("zz", base36, base::DEC).unwrap(), "1295");
+    assert_eq!(cache::len(), 1);
+
+    // Invalid tables are reported and not cached
+    assert!(matches!(
+        convert_base("1", "011", base::DEC),
+        Err(Error::DuplicateCharacter { table: Table::Source, .. })
+    ));
+    assert_eq!(cache::len(), 1);
+
+    // Bounded, evicting the least recently used pair
+    cache::set_capacity(2);
+    convert_base("1", base62, base::DEC).unwrap();
+    convert_base("zz", base36, base::DEC).unwrap();
+    assert!(cache::contains(base62, base::DEC));
+    assert!(cache::contains(base36, base::DEC));
+    // Touching the older pair makes the other one the least recently used
+    convert_base("1", base62, base::DEC).unwrap();
+    convert_base("1", base::DEC, base62).unwrap();
+    assert_eq!(cache::len(), 2);
+    assert!(cache::contains(base62, base::DEC));
+    assert!(!cache::contains(base36, base::DEC));
+    assert!(cache::contains(base::DEC, base62));
+    cache::set_capacity(1);
+    assert_eq!(cache::len(), 1);
+    assert!(cache::contains(base::DEC, base62));
+
+    // Shared between threads
+    thread::scope(|s| {
+        for i in 0..8 {
+            s.spawn(move || {
+                let table: String = base62.chars().take(20 + i).collect();
+                for n in 0..100u32 {
+                    let encoded = convert_base(&n.to_string(), base::DEC, &table).unwrap();
+                    assert_eq!(convert_base(&encoded, &table, base::DEC).unwrap(), n.to_string());
+                }
+            });
+        }
+    });
+    assert_eq!(cache::len(), 1);
+
+    // Opting out
+    cache::set_capacity(0);
+    assert_eq!(cache::len(), 0);
+    assert_eq!(convert_base("zz", base36, base::DEC).unwrap(), "1295");
+    assert_eq!(cache::len(), 0);
+
+    cache::set_capacity(cache::DEFAULT_CAPACITY);
+    convert_base("zz", base36, base::DEC).unwrap();
+    cache::clear();
+    assert_eq!(cache::len(), 0);
+    assert_eq!(cache::capacity(), cache::DEFAULT_CAPACITY);
+}
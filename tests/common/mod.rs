@@ -0,0 +1,20 @@
+//! Helpers shared by the integration tests
+
+#![allow(dead_code)]
+
+pub mod rng;
+
+use anybase::Alphabet;
+
+/// `table` with the symbols the number tests use: `-` and `+` signs, a `.`
+/// radix point and `(` `)` repetend marks
+pub fn numeric(table: &str) -> Alphabet {
+    Alphabet::new(table)
+        .unwrap()
+        .with_signs('-', Some('+'))
+        .unwrap()
+        .with_radix_point('.')
+        .unwrap()
+        .with_repetend_marks('(', ')')
+        .unwrap()
+}
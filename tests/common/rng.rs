@@ -0,0 +1,19 @@
+//! Deterministic xorshift generator, so failures are reproducible
+
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// `len` random digits below `base`
+    pub fn digits(&mut self, len: usize, base: u32) -> Vec<u32> {
+        (0..len)
+            .map(|_| (self.next() % u64::from(base)) as u32)
+            .collect()
+    }
+}
@@ -0,0 +1,246 @@
+use anybase::*;
+use std::collections::HashSet;
+use std::sync::{Arc, OnceLock};
+use std::thread;
+
+fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+
+#[test]
+fn test_owned_converter_traits() {
+    assert_send_sync_static::<Converter>();
+    assert_send_sync_static::<Alphabet>();
+
+    // Tables built at runtime, e.g. from deserialized configuration
+    let src_table: String = base::DEC.to_string();
+    let dst_table: String = "0123456789abcdefghijklmnopqrstuvwxyz".to_string();
+    let converter = Converter::try_new(src_table, dst_table).unwrap();
+
+    let cloned = converter.clone();
+    assert_eq!(converter, cloned);
+    assert_ne!(converter, converter.inverse());
+    assert_eq!(converter, converter.inverse().inverse());
+
+    let set: HashSet<Converter> = [converter.clone(), cloned, converter.inverse()]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+
+    let debug = format!("{:?}", Converter::new("01", "012"));
+    assert!(debug.starts_with(r#"Converter { src: Alphabet { table: "01", "#));
+    assert!(debug.contains(r#"dst: Alphabet { table: "012", "#));
+}
+
+#[test]
+fn test_converter_across_threads() {
+    static GLOBAL: OnceLock<Converter> = OnceLock::new();
+    let global = GLOBAL.get_or_init(|| Converter::new(base::DEC, base::HEX));
+
+    let shared = Arc::new(global.clone());
+    let handles: Vec<_> = (0..4u32)
+        .map(|i| {
+            let converter = Arc::clone(&shared);
+            thread::spawn(move || converter.convert(&(255 + i).to_string()).unwrap())
+        })
+        .collect();
+    let results: Vec<String> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(results, ["ff", "100", "101", "102"]);
+}
+
+#[test]
+fn test_power_of_two_bases_match_general_path() {
+    const BASE32: &str = "abcdefghijklmnopqrstuvwxyz234567";
+    const BASE64: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let tables = [base::BIN, "0123", base::OCT, base::HEX, BASE32, BASE64];
+    let inputs = ["0", "000", "1", "7", "0007", "10", "777777777777777777777", "1234567012345670"];
+
+    for src in tables {
+        let to_oct = Converter::new(base::OCT, src);
+        for input in inputs {
+            let input = to_oct.convert(input).unwrap();
+            for dst in tables {
+                // Going through decimal forces the general BigInt path
+                let via_decimal = convert_base(&input, src, base::DEC)
+                    .and_then(|dec| convert_base(&dec, base::DEC, dst))
+                    .unwrap();
+                assert_eq!(
+                    Converter::new(src, dst).convert(&input).unwrap(),
+                    via_decimal,
+                    "{} from {} to {}",
+                    input,
+                    src,
+                    dst
+                );
+            }
+        }
+    }
+    assert_eq!(Converter::new(base::HEX, base::BIN).convert("00ff").unwrap(), "11111111");
+    assert_eq!(Converter::new(base::BIN, base::HEX).convert("0000").unwrap(), "0");
+    assert_eq!(
+        Converter::new(base::HEX, base::BIN).convert("0x1"),
+        Err(Error::InvalidCharacter {
+            ch: 'x',
+            byte_offset: 1,
+            char_index: 1,
+        })
+    );
+}
+
+#[test]
+fn test_exact_power_bases_match_general_path() {
+    let base6 = "012345";
+    let base36 = "0123456789abcdefghijklmnopqrstuvwxyz";
+    let base27 = "ABCDEFGHIJKLMNOPQRSTUVWXYZ_";
+    let base1000: String = (0..1000u32).map(|i| char::from_u32(0x4e00 + i).unwrap()).collect();
+    let pairs = [
+        ("012", base27),
+        (base::DEC, base1000.as_str()),
+        (base6, base36),
+    ];
+    let inputs = ["0", "1", "2", "1210", "22222222", "10000000000000", "1221012021120201"];
+
+    for (small, big) in pairs {
+        let from_ternary = Converter::new("012", small);
+        for input in inputs {
+            let input = from_ternary.convert(input).unwrap();
+            for (src, dst) in [(small, big), (big, small)] {
+                let input = convert_base(&input, small, src).unwrap();
+                // Going through hex forces the general BigInt path
+                let via_hex = convert_base(&input, src, base::HEX)
+                    .and_then(|hex| convert_base(&hex, base::HEX, dst))
+                    .unwrap();
+                assert_eq!(Converter::new(src, dst).convert(&input).unwrap(), via_hex);
+            }
+        }
+    }
+    assert_eq!(Converter::new(base::DEC, base1000.as_str()).convert("0001000").unwrap(), "丁一");
+    assert_eq!(Converter::new(base36, base6).convert("00").unwrap(), "0");
+}
+
+#[test]
+fn test_same_base_transliteration() {
+    let shuffled = "9876543210";
+    let converter = Converter::new(shuffled, base::DEC);
+    assert_eq!(converter.convert("9990").unwrap(), "9");
+    assert_eq!(converter.convert("9999").unwrap(), "0");
+    assert_eq!(converter.convert("").unwrap(), "0");
+    assert_eq!(converter.transliterate("9990").unwrap(), "0009");
+    assert_eq!(converter.transliterate("").unwrap(), "");
+
+    let upper = Converter::new(base::HEX, "0123456789ABCDEF");
+    assert_eq!(upper.convert("00c0ffee").unwrap(), "C0FFEE");
+    assert_eq!(upper.transliterate("00c0ffee").unwrap(), "00C0FFEE");
+
+    assert_eq!(
+        Converter::new(base::DEC, base::HEX).transliterate("12"),
+        Err(Error::BaseMismatch {
+            src_base: 10,
+            dst_base: 16,
+        })
+    );
+}
+
+#[test]
+fn test_native_fast_path_boundaries() {
+    let base62 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let converter = Converter::new(base::DEC, base62);
+    let boundaries = [0, 1, u128::from(u64::MAX), u128::from(u64::MAX) + 1, u128::MAX];
+
+    for value in boundaries {
+        let input = value.to_string();
+        let padded = format!("000{}", input);
+        // Parsing into a BigInt and formatting it bypasses the fast path
+        let expected = converter.format(&converter.parse(&input).unwrap()).unwrap();
+        assert_eq!(converter.convert(&input).unwrap(), expected);
+        assert_eq!(converter.convert(&padded).unwrap(), expected);
+    }
+
+    // One past u128::MAX falls back to BigInt
+    let above = "340282366920938463463374607431768211456";
+    assert_eq!(
+        converter.convert(above).unwrap(),
+        converter.format(&converter.parse(above).unwrap()).unwrap()
+    );
+    assert_eq!(converter.convert("").unwrap(), "0");
+
+    // Errors after the value overflows still report the right position
+    let invalid = format!("{}x", above);
+    assert_eq!(
+        converter.convert(&invalid),
+        Err(Error::InvalidCharacter {
+            ch: 'x',
+            byte_offset: above.len(),
+            char_index: above.len(),
+        })
+    );
+}
+
+#[test]
+fn test_buffer_apis_match_convert() {
+    let cjk: String = (0..1000u32).map(|i| char::from_u32(0x4e00 + i).unwrap()).collect();
+    let converters = [
+        Converter::new(base::DEC, base::HEX),
+        Converter::new(base::DEC, cjk.as_str()),
+        Converter::new(base::HEX, base::BIN),
+    ];
+    let inputs = ["0".to_string(), "000".into(), "255".into(), "1".repeat(60), "9".repeat(3000)];
+
+    for converter in &converters {
+        for input in &inputs {
+            let input = if converter.src_base() == 16 { input.replace('9', "f") } else { input.clone() };
+            let expected = converter.convert(&input).unwrap();
+
+            let mut out = String::from("prefix");
+            converter.convert_into(&input, &mut out).unwrap();
+            assert_eq!(out, format!("prefix{}", expected));
+
+            let mut written = String::new();
+            converter.write_to(&input, &mut written).unwrap();
+            assert_eq!(written, expected);
+
+            let mut buf = vec![0u8; expected.len()];
+            assert_eq!(converter.convert_to_slice(&input, &mut buf), Ok(expected.len()));
+            assert_eq!(buf, expected.as_bytes());
+
+            let mut short = vec![b'#'; expected.len() - 1];
+            assert_eq!(
+                converter.convert_to_slice(&input, &mut short),
+                Err(Error::BufferTooSmall {
+                    needed: expected.len(),
+                    available: expected.len() - 1,
+                })
+            );
+            assert!(short.iter().all(|&b| b == b'#'));
+        }
+    }
+}
+
+#[test]
+fn test_buffer_apis_on_error() {
+    let converter = Converter::new(base::DEC, base::HEX);
+    let mut out = String::from("keep");
+    assert!(matches!(
+        converter.convert_into("12x", &mut out),
+        Err(Error::InvalidCharacter { ch: 'x', .. })
+    ));
+    assert_eq!(out, "keep");
+
+    struct Failing;
+    impl std::fmt::Write for Failing {
+        fn write_str(&mut self, _: &str) -> std::fmt::Result {
+            Err(std::fmt::Error)
+        }
+    }
+    assert_eq!(converter.write_to("255", &mut Failing), Err(Error::WriteFailed));
+
+    // A writer that converts again while being written to
+    struct Nested<'a>(&'a Converter, String);
+    impl std::fmt::Write for Nested<'_> {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.1.push_str(s);
+            self.0.inverse().write_to(&"1".repeat(50), &mut self.1).map_err(|_| std::fmt::Error)
+        }
+    }
+    let mut nested = Nested(&converter, String::new());
+    converter.write_to(&"9".repeat(50), &mut nested).unwrap();
+    assert!(nested.1.len() > 50);
+}
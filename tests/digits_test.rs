@@ -0,0 +1,75 @@
+use anybase::*;
+
+mod common;
+use common::rng::Rng;
+
+#[test]
+fn test_numeric_digits_round_trip() {
+    let mut rng = Rng(0x9e3779b97f4a7c15);
+    for base in [2, 3, 10, 60, 1000, 65536, u32::MAX] {
+        for len in [1, 2, 9, 10, 11, 300, 5000] {
+            let mut digits = rng.digits(len, base);
+            // Keep the leading digit non-zero
+            digits[0] = digits[0].max(1);
+            let value = BigInt::from_digits(&digits, base).unwrap();
+            assert_eq!(value.to_digits(base).unwrap(), digits, "base {} len {}", base, len);
+
+            let mut reversed: Vec<u32> = value.digits_le(base).unwrap().collect();
+            reversed.reverse();
+            assert_eq!(reversed, digits, "base {} len {}", base, len);
+        }
+    }
+}
+
+#[test]
+fn test_digits_match_alphabet_layer() {
+    let alphabet = Alphabet::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz").unwrap();
+    let value = alphabet.parse("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz0").unwrap();
+    let digits = value.to_digits(62).unwrap();
+    assert_eq!(alphabet.encode_digits(&digits).unwrap(), alphabet.format(&value).unwrap());
+    assert_eq!(BigInt::from_digits(&alphabet.decode_digits("00zz").unwrap(), 62).unwrap(), BigInt::from(3843u32));
+}
+
+#[test]
+fn test_digit_iterator_boundaries() {
+    assert_eq!(BigInt::zero().to_digits(10).unwrap(), [0]);
+    assert_eq!(BigInt::zero().digits_le(10).unwrap().collect::<Vec<_>>(), [0]);
+    assert_eq!(BigInt::from_digits(&[], 10).unwrap(), BigInt::zero());
+    assert_eq!(BigInt::from_digits(&[0, 0, 7], 10).unwrap(), BigInt::from(7u8));
+
+    // Exact powers of the base need zero digits inside and across chunks
+    for exp in [1, 8, 9, 10, 18, 19, 100] {
+        let power = BigInt::from(10u32).pow(exp);
+        let digits: Vec<u32> = power.digits_le(10).unwrap().collect();
+        assert_eq!(digits.len(), exp as usize + 1);
+        assert!(digits[..exp as usize].iter().all(|&d| d == 0));
+        assert_eq!(digits[exp as usize], 1);
+    }
+
+    // Lazy: the low digits of a huge value are available immediately
+    let huge = BigInt::from(7u32).pow(100_000);
+    let mut low = huge.digits_le(10).unwrap();
+    assert_eq!(low.by_ref().take(3).collect::<Vec<_>>(), [1, 0, 0]);
+    assert!(low.next().is_some());
+}
+
+#[test]
+fn test_digit_errors() {
+    assert_eq!(
+        BigInt::from_digits(&[1, 60, 2], 60),
+        Err(Error::InvalidDigit {
+            digit: 60,
+            index: 1,
+            base: 60,
+        })
+    );
+    assert_eq!(BigInt::from_digits(&[0], 1), Err(Error::InvalidBase { base: 1 }));
+    assert_eq!(BigInt::from_digits(&[], 0), Err(Error::InvalidBase { base: 0 }));
+}
+
+#[test]
+fn test_to_digits_rejects_base_one() {
+    let value = BigInt::from(5u8);
+    assert_eq!(value.to_digits(1), Err(Error::InvalidBase { base: 1 }));
+    assert!(matches!(value.digits_le(0), Err(Error::InvalidBase { base: 0 })));
+}
@@ -25,15 +25,34 @@ fn test_same_source_destination() {
 }
 
 #[test]
-#[should_panic(expected = "src_table is empty")]
 fn test_empty_src_table() {
-    convert_base("123", "", "0123456789").unwrap();
+    assert_eq!(
+        convert_base("123", "", "0123456789"),
+        Err(Error::EmptyTable {
+            table: Table::Source
+        })
+    );
 }
 
 #[test]
-#[should_panic(expected = "dst_table is empty")]
 fn test_empty_dst_table() {
-    convert_base("123", "0123456789", "").unwrap();
+    assert_eq!(
+        convert_base("123", "0123456789", ""),
+        Err(Error::EmptyTable {
+            table: Table::Destination
+        })
+    );
+}
+
+#[test]
+fn test_single_char_table_rejected() {
+    assert_eq!(
+        convert_base("1", "01", "0").err(),
+        Some(Error::SingleCharacterTable {
+            table: Table::Destination
+        })
+    );
+    assert!(Converter::try_new("a", "01").is_err());
 }
 
 #[test]
@@ -45,4 +64,17 @@ fn test_extreme_large_integer() {
     let bin_res = convert_base(&large_input, hex_table, bin_table).unwrap();
     let hex_res = convert_base(&bin_res, bin_table, hex_table).unwrap();
     assert_eq!(large_input, hex_res);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_invalid_character_position() {
+    let err = convert_base("你好x", "你好世界", "01").unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidCharacter {
+            ch: 'x',
+            byte_offset: 6,
+            char_index: 2,
+        }
+    );
+}
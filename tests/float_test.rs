@@ -0,0 +1,322 @@
+use anybase::*;
+
+mod common;
+use common::numeric;
+use common::rng::Rng;
+
+/// Significant digits of a decimal string, without sign, point or exponent
+fn significant_digits(s: &str) -> String {
+    let mantissa = s.split('e').next().unwrap().trim_start_matches('-');
+    let digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+    digits.trim_matches('0').to_string()
+}
+
+#[test]
+fn test_format_matches_std_shortest_digits() {
+    let dec = numeric(base::DEC).with_exponent_marker('e').unwrap();
+    // Random bit patterns cover every exponent, including subnormals
+    let mut rng = Rng(0x9e3779b97f4a7c15);
+    let values = (0..5_000)
+        .map(|_| f64::from_bits(rng.next()))
+        .filter(|v| v.is_finite())
+        .chain([
+            0.1,
+            0.3,
+            1e21,
+            1e-7,
+            123.456,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            2f64.powi(-1022) - 5e-324,
+            9007199254740993.0,
+        ]);
+    for v in values {
+        let formatted = dec.format_float(v).unwrap();
+        assert_eq!(
+            significant_digits(&formatted),
+            significant_digits(&format!("{:e}", v)),
+            "{:e}",
+            v
+        );
+        assert_eq!(
+            dec.parse_float::<f64>(&formatted).unwrap(),
+            v,
+            "{}",
+            formatted
+        );
+    }
+
+    let mut rng = Rng(0x9e3779b97f4a7c15);
+    let values = (0..5_000)
+        .map(|_| f32::from_bits(rng.next() as u32))
+        .filter(|v| v.is_finite())
+        .chain([0.1, f32::MAX, f32::MIN_POSITIVE, 1e-45, 16777217.0]);
+    for v in values {
+        let formatted = dec.format_float(v).unwrap();
+        assert_eq!(
+            significant_digits(&formatted),
+            significant_digits(&format!("{:e}", v)),
+            "{:e}",
+            v
+        );
+        assert_eq!(
+            dec.parse_float::<f32>(&formatted).unwrap(),
+            v,
+            "{}",
+            formatted
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_in_any_base() {
+    for (table, marker) in [
+        (base::BIN, 'p'),
+        ("012", 'e'),
+        (base::OCT, 'e'),
+        (base::HEX, 'p'),
+        ("0123456789abcdefghijklmnopqrstuvwxyz", '^'),
+        (
+            "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+            '^',
+        ),
+    ] {
+        let alphabet = numeric(table).with_exponent_marker(marker).unwrap();
+        let mut rng = Rng(0x9e3779b97f4a7c15);
+        for _ in 0..2_000 {
+            let bits = rng.next();
+            let v = f64::from_bits(bits);
+            if v.is_finite() {
+                let formatted = alphabet.format_float(v).unwrap();
+                assert_eq!(
+                    alphabet.parse_float::<f64>(&formatted).unwrap(),
+                    v,
+                    "{}",
+                    formatted
+                );
+            }
+            let v = f32::from_bits(bits as u32);
+            if v.is_finite() {
+                let formatted = alphabet.format_float(v).unwrap();
+                assert_eq!(
+                    alphabet.parse_float::<f32>(&formatted).unwrap(),
+                    v,
+                    "{}",
+                    formatted
+                );
+            }
+        }
+    }
+
+    let hex = numeric(base::HEX).with_exponent_marker('p').unwrap();
+    for (v, expected) in [
+        (1.0, "1"),
+        (0.5, "0.8"),
+        (-255.5, "-ff.8"),
+        (1.0 / 3.0, "0.55555555555554"),
+        (2f64.powi(-40), "1p-a"),
+        (2f64.powi(84), "1p15"),
+        (2f64.powi(80), "100000000000000000000"),
+        (5e-324, "4p-10d"),
+        (f64::MAX, "f.ffffffffffff8pff"),
+    ] {
+        assert_eq!(hex.format_float(v).unwrap(), expected, "{:e}", v);
+    }
+}
+
+#[test]
+fn test_parse_is_correctly_rounded() {
+    let dec = numeric(base::DEC).with_exponent_marker('e').unwrap();
+    let mut inputs: Vec<String> = [
+        "9007199254740993",
+        "9007199254740995",
+        "9007199254740993.0000000000000000000000000001",
+        "2.4703282292062327e-324",
+        "2.4703282292062328e-324",
+        "4.9406564584124654e-324",
+        "2.2250738585072011e-308",
+        "2.2250738585072012e-308",
+        "1.7976931348623157e308",
+        "1.7976931348623158e308",
+        "1.7976931348623159e308",
+        "0.1",
+        "-0.0",
+        "123456789012345678901234567890e-40",
+        "1e-400",
+        "1e400",
+        ".5",
+        "5.",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    // Long digit strings near and between representable values
+    let mut rng = Rng(0x9e3779b97f4a7c15);
+    for _ in 0..1_000 {
+        let bits = rng.next();
+        let digits = format!("{:020}", bits);
+        let exp = (bits % 700) as i32 - 350;
+        inputs.push(format!("{}.{}e{}", &digits[..1], &digits[1..], exp));
+        inputs.push(format!("{}e{}", digits, exp - 20));
+    }
+    for input in &inputs {
+        let parsed = dec.parse_float::<f64>(input).unwrap();
+        let expected: f64 = input.parse().unwrap();
+        assert_eq!(parsed.to_bits(), expected.to_bits(), "{}", input);
+        let parsed = dec.parse_float::<f32>(input).unwrap();
+        let expected: f32 = input.parse().unwrap();
+        assert_eq!(parsed.to_bits(), expected.to_bits(), "{}", input);
+    }
+
+    let bin = numeric(base::BIN).with_exponent_marker('p').unwrap();
+    assert_eq!(bin.parse_float::<f64>("1.1p-10").unwrap(), 0.375);
+    assert_eq!(bin.parse_float::<f64>("1p-10000110010").unwrap(), 5e-324);
+    // Half of the smallest subnormal rounds to even, which is zero
+    assert_eq!(bin.parse_float::<f64>("1p-10000110011").unwrap(), 0.0);
+    assert_eq!(bin.parse_float::<f64>("1.1p-10000110011").unwrap(), 5e-324);
+    assert_eq!(
+        bin.parse_float::<f64>(&format!("1p{}", "1".repeat(100)))
+            .unwrap(),
+        f64::INFINITY
+    );
+    assert_eq!(
+        bin.parse_float::<f64>(&format!("-1p-{}", "1".repeat(100)))
+            .unwrap()
+            .to_bits(),
+        (-0.0f64).to_bits()
+    );
+}
+
+#[test]
+fn test_special_values() {
+    let dec = numeric(base::DEC).with_exponent_marker('e').unwrap();
+    assert_eq!(dec.format_float(f64::NAN).unwrap(), "NaN");
+    assert_eq!(dec.format_float(-f32::NAN).unwrap(), "NaN");
+    assert_eq!(dec.format_float(f64::INFINITY).unwrap(), "∞");
+    assert_eq!(dec.format_float(f32::NEG_INFINITY).unwrap(), "-∞");
+    assert_eq!(dec.format_float(0.0f64).unwrap(), "0");
+    assert_eq!(dec.format_float(-0.0f64).unwrap(), "-0");
+    assert!(dec.parse_float::<f64>("NaN").unwrap().is_nan());
+    assert_eq!(dec.parse_float::<f64>("-∞").unwrap(), f64::NEG_INFINITY);
+    assert_eq!(dec.parse_float::<f32>("∞").unwrap(), f32::INFINITY);
+    assert!(dec.parse_float::<f64>("-0").unwrap().is_sign_negative());
+    assert!(dec.parse_float::<f64>("-0e5").unwrap().is_sign_negative());
+
+    // A table that can spell NaN reads it as a number
+    let base62 = numeric("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz")
+        .with_exponent_marker('^')
+        .unwrap();
+    assert_eq!(base62.format_float(f64::NAN), Err(Error::NonFiniteValue));
+    assert_eq!(base62.format_float(f64::INFINITY).unwrap(), "∞");
+    assert_eq!(base62.parse_float::<f64>("NaN").unwrap(), 90667.0);
+
+    // Without signs, negative zero loses its sign and other negative values
+    // cannot be written
+    let unsigned = Alphabet::new(base::DEC)
+        .unwrap()
+        .with_radix_point('.')
+        .unwrap();
+    assert_eq!(unsigned.format_float(-0.0f64).unwrap(), "0");
+    assert_eq!(unsigned.format_float(-1.5f64), Err(Error::NegativeValue));
+    assert_eq!(
+        unsigned.format_float(f64::NEG_INFINITY),
+        Err(Error::NegativeValue)
+    );
+}
+
+#[test]
+fn test_notation() {
+    let dec = numeric(base::DEC).with_exponent_marker('e').unwrap();
+    for (v, expected) in [
+        (1e20, "100000000000000000000"),
+        (1e21, "1e21"),
+        (1.5e21, "1.5e21"),
+        (1e-6, "0.000001"),
+        (1.5e-7, "1.5e-7"),
+        (-123.456, "-123.456"),
+        (f64::MAX, "1.7976931348623157e308"),
+        (5e-324, "5e-324"),
+    ] {
+        assert_eq!(dec.format_float(v).unwrap(), expected);
+    }
+
+    // Without a marker, values are always written in fixed notation
+    let fixed = Alphabet::new(base::DEC)
+        .unwrap()
+        .with_radix_point('.')
+        .unwrap();
+    assert_eq!(fixed.format_float(1e21).unwrap(), "1000000000000000000000");
+    assert_eq!(fixed.format_float(1.5e-7).unwrap(), "0.00000015");
+    assert_eq!(fixed.parse_float::<f64>("1.5e-7"), Err(invalid('e', 3)));
+
+    // Negative exponents need a negative sign, and several digits a radix
+    // point; integers need neither
+    let unsigned = Alphabet::new(base::DEC)
+        .unwrap()
+        .with_radix_point('.')
+        .unwrap()
+        .with_exponent_marker('e')
+        .unwrap();
+    assert_eq!(unsigned.format_float(1e-7).unwrap(), "0.0000001");
+    assert_eq!(unsigned.format_float(1e22).unwrap(), "1e22");
+    let integral = Alphabet::new(base::DEC)
+        .unwrap()
+        .with_exponent_marker('e')
+        .unwrap();
+    assert_eq!(integral.format_float(1e22).unwrap(), "1e22");
+    assert_eq!(
+        integral.format_float(1.5e22).unwrap(),
+        "15000000000000000000000"
+    );
+    assert_eq!(integral.format_float(0.5), Err(Error::FractionalValue));
+    assert_eq!(integral.parse_float::<f64>("15e1").unwrap(), 150.0);
+}
+
+fn invalid(ch: char, offset: usize) -> Error {
+    Error::InvalidCharacter {
+        ch,
+        byte_offset: offset,
+        char_index: offset,
+    }
+}
+
+#[test]
+fn test_parse_float_errors() {
+    let dec = numeric(base::DEC).with_exponent_marker('e').unwrap();
+    assert_eq!(dec.parse_float::<f64>("1e"), Err(invalid('e', 1)));
+    assert_eq!(dec.parse_float::<f64>("1e-"), Err(invalid('e', 1)));
+    assert_eq!(dec.parse_float::<f64>("1e5e5"), Err(invalid('e', 3)));
+    assert_eq!(dec.parse_float::<f64>("1e5.5"), Err(invalid('.', 3)));
+    assert_eq!(dec.parse_float::<f64>("-1.5x"), Err(invalid('x', 4)));
+    assert_eq!(dec.parse_float::<f64>("1e--5"), Err(invalid('-', 3)));
+    assert_eq!(dec.parse_float::<f64>("nan"), Err(invalid('n', 0)));
+
+    assert_eq!(
+        Converter::new(base::DEC, base::HEX).with_exponent_marker('e'),
+        Err(Error::ExponentConflict {
+            table: Table::Destination,
+            ch: 'e'
+        })
+    );
+    let pointed = Alphabet::new(base::DEC)
+        .unwrap()
+        .with_radix_point('.')
+        .unwrap();
+    assert_eq!(
+        pointed.clone().with_exponent_marker('.'),
+        Err(Error::ExponentConflict {
+            table: Table::Alphabet,
+            ch: '.'
+        })
+    );
+    let marked = pointed.with_exponent_marker('e').unwrap();
+    assert_eq!(marked.exponent_marker(), Some('e'));
+    assert_eq!(
+        marked.with_signs('e', None),
+        Err(Error::SignConflict {
+            table: Table::Alphabet,
+            ch: 'e'
+        })
+    );
+}
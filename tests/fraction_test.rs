@@ -0,0 +1,220 @@
+use anybase::*;
+
+mod common;
+use common::numeric;
+
+#[test]
+fn test_convert_fraction() {
+    let hex_to_dec = Converter::from_alphabets(numeric(base::HEX), numeric(base::DEC));
+    for (input, expected) in [
+        ("ff.8", "255.5"),
+        ("-ff.8", "-255.5"),
+        ("0.1", "0.0625"),
+        (".c", "0.75"),
+        ("10.", "16"),
+        ("0.000", "0"),
+        ("-0.0", "0"),
+        ("", "0"),
+    ] {
+        let output = hex_to_dec
+            .convert_fraction(input, 10, Rounding::HalfEven)
+            .unwrap();
+        assert_eq!(output.as_str(), expected, "{}", input);
+        assert!(output.is_exact());
+    }
+
+    let dec_to_bin = Converter::from_alphabets(numeric(base::DEC), numeric(base::BIN));
+    let output = dec_to_bin
+        .convert_fraction("0.1", 20, Rounding::Truncate)
+        .unwrap();
+    assert_eq!(output.to_string(), "0.00011001100110011001");
+    assert!(!output.is_exact());
+    let output = dec_to_bin
+        .convert_fraction("0.1", 4, Rounding::Truncate)
+        .unwrap();
+    assert_eq!(output.into_string(), "0.0001");
+    // Rounding can carry into the integer part
+    let output = dec_to_bin
+        .convert_fraction("0.99", 3, Rounding::HalfEven)
+        .unwrap();
+    assert_eq!(output.as_str(), "1");
+    assert!(!output.is_exact());
+
+    // Large values go through the big integer paths
+    let input = format!("{}.{}", "9".repeat(300), "9".repeat(300));
+    let output = Converter::from_alphabets(numeric(base::DEC), numeric(base::DEC))
+        .convert_fraction(&input, 299, Rounding::HalfUp)
+        .unwrap();
+    assert_eq!(output.as_str(), format!("1{}", "0".repeat(300)));
+}
+
+#[test]
+fn test_rounding_modes() {
+    let converter = Converter::from_alphabets(numeric(base::DEC), numeric(base::DEC));
+    let modes = [
+        Rounding::Truncate,
+        Rounding::HalfEven,
+        Rounding::HalfUp,
+        Rounding::Ceiling,
+        Rounding::Floor,
+    ];
+    for (input, expected) in [
+        ("2.5", ["2", "2", "3", "3", "2"]),
+        ("3.5", ["3", "4", "4", "4", "3"]),
+        ("2.4", ["2", "2", "2", "3", "2"]),
+        ("2.6", ["2", "3", "3", "3", "2"]),
+        ("-2.5", ["-2", "-2", "-3", "-2", "-3"]),
+        ("-2.6", ["-2", "-3", "-3", "-2", "-3"]),
+        ("-0.4", ["0", "0", "0", "0", "-1"]),
+        ("7", ["7", "7", "7", "7", "7"]),
+    ] {
+        for (mode, expected) in modes.iter().zip(expected) {
+            let output = converter.convert_fraction(input, 0, *mode).unwrap();
+            assert_eq!(output.as_str(), expected, "{} {:?}", input, mode);
+        }
+    }
+    assert_eq!(Rounding::default(), Rounding::HalfEven);
+
+    // Ties go to an even last digit, which in odd bases is not the same as
+    // an even value: 4 and 3 are 11 and 10 in base 3
+    let to_ternary = Converter::from_alphabets(numeric(base::DEC), numeric("012"));
+    for (input, expected) in [("1.5", "2"), ("4.5", "12"), ("3.5", "10")] {
+        let output = to_ternary
+            .convert_fraction(input, 0, Rounding::HalfEven)
+            .unwrap();
+        assert_eq!(output.as_str(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_fraction_errors() {
+    let converter = Converter::from_alphabets(numeric(base::HEX), numeric(base::DEC));
+    assert_eq!(
+        converter.convert_fraction("1.2.3", 5, Rounding::HalfEven),
+        Err(Error::InvalidCharacter {
+            ch: '.',
+            byte_offset: 3,
+            char_index: 3
+        })
+    );
+    assert_eq!(
+        converter.convert_fraction("-", 5, Rounding::HalfEven),
+        Err(Error::InvalidCharacter {
+            ch: '-',
+            byte_offset: 0,
+            char_index: 0
+        })
+    );
+    // A radix point needs a digit on at least one side
+    for (input, offset) in [(".", 0), ("-.", 1), ("+.", 1)] {
+        assert_eq!(
+            converter.convert_fraction(input, 5, Rounding::HalfEven),
+            Err(Error::InvalidCharacter {
+                ch: '.',
+                byte_offset: offset,
+                char_index: offset
+            })
+        );
+    }
+    assert_eq!(
+        converter.convert_fraction("-1x.2", 5, Rounding::HalfEven),
+        Err(Error::InvalidCharacter {
+            ch: 'x',
+            byte_offset: 2,
+            char_index: 2
+        })
+    );
+    let too_long = Err(Error::FractionLimitExceeded {
+        limit: MAX_FRACTION_DIGITS,
+    });
+    assert_eq!(
+        converter.convert_fraction("1.8", usize::MAX, Rounding::HalfEven),
+        too_long
+    );
+    // Rejected before computing base^precision
+    assert_eq!(
+        converter.convert_fraction("1", 4_000_000_000, Rounding::HalfEven),
+        too_long
+    );
+    assert!(
+        converter
+            .convert_fraction("1", MAX_FRACTION_DIGITS, Rounding::HalfEven)
+            .is_ok()
+    );
+    let input = format!("0.{}", "1".repeat(MAX_FRACTION_DIGITS + 1));
+    assert_eq!(
+        converter.convert_fraction(&input, 5, Rounding::HalfEven),
+        too_long
+    );
+
+    // Without a radix point, '.' is an invalid character and the output is
+    // rounded to an integer
+    let plain = Converter::new(base::HEX, base::DEC);
+    assert_eq!(
+        plain.convert_fraction("1.8", 5, Rounding::HalfEven),
+        Err(Error::InvalidCharacter {
+            ch: '.',
+            byte_offset: 1,
+            char_index: 1
+        })
+    );
+    let src = Alphabet::new(base::HEX)
+        .unwrap()
+        .with_radix_point('.')
+        .unwrap();
+    let converter = Converter::from_alphabets(src, Alphabet::new(base::DEC).unwrap());
+    assert_eq!(
+        converter
+            .convert_fraction("1.8", 5, Rounding::HalfUp)
+            .unwrap()
+            .as_str(),
+        "2"
+    );
+    assert_eq!(
+        converter
+            .convert_fraction("1.8", 5, Rounding::Floor)
+            .unwrap()
+            .as_str(),
+        "1"
+    );
+
+    let unsigned = Converter::new(base::DEC, base::HEX)
+        .with_radix_point('.')
+        .unwrap();
+    assert_eq!(
+        unsigned.convert_fraction("-1", 5, Rounding::HalfEven),
+        Err(Error::InvalidCharacter {
+            ch: '-',
+            byte_offset: 0,
+            char_index: 0
+        })
+    );
+
+    assert_eq!(
+        Converter::new(base::DEC, "0123456789.").with_radix_point('.'),
+        Err(Error::RadixPointConflict {
+            table: Table::Destination,
+            ch: '.'
+        })
+    );
+    let signed = Alphabet::new(base::DEC)
+        .unwrap()
+        .with_signs('-', Some('+'))
+        .unwrap();
+    assert_eq!(
+        signed.clone().with_radix_point('+'),
+        Err(Error::RadixPointConflict {
+            table: Table::Alphabet,
+            ch: '+'
+        })
+    );
+    let pointed = signed.with_radix_point(',').unwrap();
+    assert_eq!(pointed.radix_point(), Some(','));
+    assert_eq!(
+        pointed.with_signs(',', None),
+        Err(Error::SignConflict {
+            table: Table::Alphabet,
+            ch: ','
+        })
+    );
+}
@@ -0,0 +1,53 @@
+use anybase::*;
+
+const BASE62: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+#[test]
+fn test_native_matches_bigint_path() {
+    let alphabet = Alphabet::new(BASE62).unwrap();
+    let values = [
+        0u128,
+        1,
+        61,
+        62,
+        u64::MAX as u128 - 1,
+        u64::MAX as u128,
+        u64::MAX as u128 + 1,
+        u128::MAX - 1,
+        u128::MAX,
+    ];
+    for value in values {
+        let expected = convert_base(&value.to_string(), base::DEC, BASE62).unwrap();
+        assert_eq!(alphabet.encode_u128(value), expected);
+        assert_eq!(alphabet.decode::<u128>(&expected).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_native_integer_types() {
+    let converter = Converter::new(base::HEX, base::BIN);
+    assert_eq!(converter.encode(5u8).unwrap(), "101");
+    assert_eq!(converter.encode(i64::MAX).unwrap(), "1".repeat(63));
+    assert_eq!(converter.encode(-5i16), Err(Error::NegativeValue));
+
+    assert_eq!(converter.decode::<u8>("ff").unwrap(), u8::MAX);
+    assert_eq!(converter.decode::<i8>("7f").unwrap(), i8::MAX);
+    assert_eq!(converter.decode::<i8>("80"), Err(Error::Overflow { ty: "i8" }));
+    assert_eq!(converter.decode::<u64>(&"f".repeat(16)).unwrap(), u64::MAX);
+    assert_eq!(
+        converter.decode::<u64>(&"f".repeat(17)),
+        Err(Error::Overflow { ty: "u64" })
+    );
+    assert_eq!(
+        converter.decode::<u128>(&"1".repeat(33)),
+        Err(Error::Overflow { ty: "u128" })
+    );
+    assert_eq!(
+        converter.decode::<u32>("0g"),
+        Err(Error::InvalidCharacter {
+            ch: 'g',
+            byte_offset: 1,
+            char_index: 1,
+        })
+    );
+}
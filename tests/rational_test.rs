@@ -0,0 +1,264 @@
+use anybase::*;
+
+mod common;
+use common::numeric;
+
+/// Enough fraction digits for every value below
+const MAX_DIGITS: usize = 1000;
+
+fn big(v: i64) -> BigInt {
+    BigInt::from(v)
+}
+
+#[test]
+fn test_format_rational() {
+    let dec = numeric(base::DEC);
+    for (numerator, denominator, expected) in [
+        (1, 3, "0.(3)"),
+        (1, 6, "0.1(6)"),
+        (1, 7, "0.(142857)"),
+        (22, 7, "3.(142857)"),
+        (1, 8, "0.125"),
+        (-1, 12, "-0.08(3)"),
+        (1, -12, "-0.08(3)"),
+        (-1, -12, "0.08(3)"),
+        (10, 5, "2"),
+        (0, -5, "0"),
+        (1, 1, "1"),
+        (1, 99, "0.(01)"),
+        (1, 30, "0.0(3)"),
+    ] {
+        assert_eq!(
+            dec.format_rational(&big(numerator), &big(denominator), MAX_DIGITS)
+                .unwrap(),
+            expected,
+            "{}/{}",
+            numerator,
+            denominator
+        );
+    }
+    // The repetend of 1/97 has the maximum length of 96 digits
+    let period = dec.format_rational(&big(1), &big(97), MAX_DIGITS).unwrap();
+    assert_eq!(period.len(), "0.()".len() + 96);
+
+    assert_eq!(
+        numeric(base::BIN)
+            .format_rational(&big(1), &big(10), MAX_DIGITS)
+            .unwrap(),
+        "0.0(0011)"
+    );
+    assert_eq!(
+        numeric("0123456789AB")
+            .format_rational(&big(1), &big(3), MAX_DIGITS)
+            .unwrap(),
+        "0.4"
+    );
+    assert_eq!(
+        numeric(base::HEX)
+            .format_rational(&big(1), &big(3), MAX_DIGITS)
+            .unwrap(),
+        "0.(5)"
+    );
+}
+
+#[test]
+fn test_format_rational_errors() {
+    let dec = numeric(base::DEC);
+    assert_eq!(
+        dec.format_rational(&big(1), &big(0), MAX_DIGITS),
+        Err(Error::ZeroDenominator)
+    );
+
+    // The limit counts the non-repeating and the repeating fraction digits
+    let limit = |limit| Error::FractionLimitExceeded { limit };
+    assert_eq!(dec.format_rational(&big(1), &big(97), 95), Err(limit(95)));
+    assert!(dec.format_rational(&big(1), &big(97), 96).is_ok());
+    assert_eq!(dec.format_rational(&big(1), &big(1024), 9), Err(limit(9)));
+    assert_eq!(
+        dec.format_rational(&big(1), &big(1024), 10).unwrap(),
+        "0.0009765625"
+    );
+    assert_eq!(dec.format_rational(&big(1), &big(6), 1), Err(limit(1)));
+    assert_eq!(dec.format_rational(&big(7), &big(1), 0).unwrap(), "7");
+    // A short input can have a repetend too long to compute
+    let converter = Converter::new(base::DEC, base::BIN)
+        .with_radix_point('.')
+        .unwrap()
+        .with_repetend_marks('(', ')')
+        .unwrap();
+    assert_eq!(
+        converter.convert_rational("0.(000000000000000000001)", MAX_DIGITS),
+        Err(limit(MAX_DIGITS))
+    );
+
+    let plain = Alphabet::new(base::DEC).unwrap();
+    assert_eq!(
+        plain.format_rational(&big(6), &big(3), MAX_DIGITS).unwrap(),
+        "2"
+    );
+    assert_eq!(
+        plain.format_rational(&big(1), &big(2), MAX_DIGITS),
+        Err(Error::FractionalValue)
+    );
+    assert_eq!(
+        plain.format_rational(&big(-1), &big(2), MAX_DIGITS),
+        Err(Error::NegativeValue)
+    );
+    let pointed = plain.with_radix_point('.').unwrap();
+    assert_eq!(
+        pointed
+            .format_rational(&big(1), &big(2), MAX_DIGITS)
+            .unwrap(),
+        "0.5"
+    );
+    assert_eq!(
+        pointed.format_rational(&big(1), &big(3), MAX_DIGITS),
+        Err(Error::RepeatingValue)
+    );
+}
+
+#[test]
+fn test_parse_rational() {
+    let dec = numeric(base::DEC);
+    for (input, numerator, denominator) in [
+        ("0.(3)", 1, 3),
+        ("0.3(3)", 1, 3),
+        ("0.(33)", 1, 3),
+        ("0.(9)", 1, 1),
+        ("-0.08(3)", -1, 12),
+        ("3.(142857)", 22, 7),
+        ("0.125", 1, 8),
+        ("2.50", 5, 2),
+        ("42", 42, 1),
+        (".(6)", 2, 3),
+        ("-0", 0, 1),
+        ("", 0, 1),
+    ] {
+        assert_eq!(
+            dec.parse_rational(input).unwrap(),
+            (big(numerator), big(denominator)),
+            "{}",
+            input
+        );
+    }
+
+    // Formatting and parsing round trip across bases
+    let converter = Converter::new(base::DEC, base::BIN)
+        .with_signs('-', None)
+        .unwrap()
+        .with_radix_point('.')
+        .unwrap()
+        .with_repetend_marks('(', ')')
+        .unwrap();
+    assert_eq!(
+        converter.convert_rational("0.1", MAX_DIGITS).unwrap(),
+        "0.0(0011)"
+    );
+    assert_eq!(
+        converter.convert_rational("-2.(3)", MAX_DIGITS).unwrap(),
+        "-10.(01)"
+    );
+    assert_eq!(
+        converter
+            .inverse()
+            .convert_rational("0.0(0011)", MAX_DIGITS)
+            .unwrap(),
+        "0.1"
+    );
+    for denominator in 1..50 {
+        for numerator in -60..60 {
+            let formatted = converter
+                .format_rational(&big(numerator), &big(denominator), MAX_DIGITS)
+                .unwrap();
+            let (n, d) = converter.inverse().parse_rational(&formatted).unwrap();
+            assert_eq!(n * big(denominator), big(numerator) * d, "{}", formatted);
+        }
+    }
+}
+
+#[test]
+fn test_parse_rational_errors() {
+    let dec = numeric(base::DEC);
+    let invalid = |ch, byte_offset, char_index| Error::InvalidCharacter {
+        ch,
+        byte_offset,
+        char_index,
+    };
+    assert_eq!(dec.parse_rational("0.1(3"), Err(invalid('(', 3, 3)));
+    assert_eq!(dec.parse_rational("0.1()"), Err(invalid(')', 4, 4)));
+    assert_eq!(dec.parse_rational("0.(3)4"), Err(invalid('4', 5, 5)));
+    assert_eq!(dec.parse_rational("0.(3)(3)"), Err(invalid('(', 5, 5)));
+    assert_eq!(dec.parse_rational("0.(3x)"), Err(invalid('x', 4, 4)));
+    assert_eq!(dec.parse_rational("-1x.5"), Err(invalid('x', 2, 2)));
+    assert_eq!(dec.parse_rational("1.5)"), Err(invalid(')', 3, 3)));
+    assert_eq!(dec.parse_rational("-"), Err(invalid('-', 0, 0)));
+    // Repetends belong to the fraction part
+    assert_eq!(dec.parse_rational("(3)"), Err(invalid('(', 0, 0)));
+
+    let unicode = Alphabet::new(base::DEC)
+        .unwrap()
+        .with_radix_point('·')
+        .unwrap()
+        .with_repetend_marks('⟨', '⟩')
+        .unwrap();
+    assert_eq!(
+        unicode
+            .format_rational(&big(1), &big(6), MAX_DIGITS)
+            .unwrap(),
+        "0·1⟨6⟩"
+    );
+    assert_eq!(unicode.parse_rational("0·1⟨6⟩").unwrap(), (big(1), big(6)));
+    assert_eq!(unicode.parse_rational("0·⟨6"), Err(invalid('⟨', 3, 2)));
+    assert_eq!(unicode.parse_rational("0·⟨6⟩x"), Err(invalid('x', 10, 5)));
+}
+
+#[test]
+fn test_repetend_configuration() {
+    let dec = Alphabet::new(base::DEC).unwrap();
+    assert_eq!(dec.repetend_marks(), None);
+    assert_eq!(
+        dec.clone().with_repetend_marks('(', '('),
+        Err(Error::RepetendConflict {
+            table: Table::Alphabet,
+            ch: '('
+        })
+    );
+    assert_eq!(
+        dec.clone().with_repetend_marks('(', '9'),
+        Err(Error::RepetendConflict {
+            table: Table::Alphabet,
+            ch: '9'
+        })
+    );
+    let pointed = dec.with_radix_point('.').unwrap();
+    assert_eq!(
+        pointed.clone().with_repetend_marks('.', ')'),
+        Err(Error::RepetendConflict {
+            table: Table::Alphabet,
+            ch: '.'
+        })
+    );
+    let marked = pointed.with_repetend_marks('[', ']').unwrap();
+    assert_eq!(marked.repetend_marks(), Some(('[', ']')));
+    assert_eq!(
+        marked.clone().with_radix_point('['),
+        Err(Error::RadixPointConflict {
+            table: Table::Alphabet,
+            ch: '['
+        })
+    );
+    assert_eq!(
+        marked.with_signs(']', None),
+        Err(Error::SignConflict {
+            table: Table::Alphabet,
+            ch: ']'
+        })
+    );
+    assert_eq!(
+        Converter::new(base::DEC, "01()").with_repetend_marks('(', ')'),
+        Err(Error::RepetendConflict {
+            table: Table::Destination,
+            ch: '('
+        })
+    );
+}
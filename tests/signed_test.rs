@@ -0,0 +1,337 @@
+use anybase::*;
+
+#[test]
+fn test_signed_conversion() {
+    let converter = Converter::new(base::DEC, "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ")
+        .with_signs('-', Some('+'))
+        .unwrap();
+    for (input, expected) in [
+        ("-35", "-Z"),
+        ("+35", "Z"),
+        ("35", "Z"),
+        ("-0", "0"),
+        ("+0", "0"),
+        ("-000", "0"),
+        (
+            "-340282366920938463463374607431768211456",
+            "-F5LXX1ZZ5PNORYNQGLHZMSP34",
+        ),
+    ] {
+        assert_eq!(converter.convert(input).unwrap(), expected, "{}", input);
+        assert_eq!(
+            converter.parse(input).unwrap().is_negative(),
+            expected.starts_with('-')
+        );
+    }
+    // A sign needs digits after it
+    for input in ["-", "+"] {
+        let ch = input.chars().next().unwrap();
+        let error = Error::InvalidCharacter {
+            ch,
+            byte_offset: 0,
+            char_index: 0,
+        };
+        assert_eq!(converter.convert(input), Err(error.clone()));
+        assert_eq!(converter.parse(input), Err(error.clone()));
+        assert_eq!(input.parse::<BigInt>(), Err(error));
+    }
+    assert_eq!(
+        converter
+            .inverse()
+            .convert("-F5LXX1ZZ5PNORYNQGLHZMSP34")
+            .unwrap(),
+        "-340282366920938463463374607431768211456"
+    );
+
+    // Every strategy carries the sign
+    for (src, dst) in [
+        ("012", base::DEC),
+        ("012", "abc"),
+        ("012", "012345678"),
+        ("012345678", "012"),
+        (base::HEX, base::BIN),
+    ] {
+        let converter = Converter::new(src, dst).with_signs('-', None).unwrap();
+        let expected = converter.format(&-converter.parse("1201").unwrap()).unwrap();
+        assert_eq!(converter.convert("-1201").unwrap(), expected);
+        assert!(expected.starts_with('-'));
+    }
+    let converter = Converter::new(base::HEX, base::HEX.to_uppercase())
+        .with_signs('-', None)
+        .unwrap();
+    assert_eq!(converter.transliterate("-00ff").unwrap(), "-00FF");
+    assert_eq!(converter.transliterate("-000").unwrap(), "000");
+
+    // Signs must appear only once, at the start
+    assert_eq!(
+        converter.convert("--1"),
+        Err(Error::InvalidCharacter {
+            ch: '-',
+            byte_offset: 1,
+            char_index: 1
+        })
+    );
+    assert_eq!(
+        converter.convert("-1+"),
+        Err(Error::InvalidCharacter {
+            ch: '+',
+            byte_offset: 2,
+            char_index: 2
+        })
+    );
+}
+
+#[test]
+fn test_buffer_apis_with_signs() {
+    let converter = Converter::new(base::DEC, "○●")
+        .with_signs('−', None)
+        .unwrap();
+    assert_eq!(
+        converter.convert("-5"),
+        Err(Error::InvalidCharacter {
+            ch: '-',
+            byte_offset: 0,
+            char_index: 0
+        })
+    );
+    assert_eq!(converter.convert("−5").unwrap(), "−●○●");
+
+    let mut out = String::from(">");
+    converter.convert_into("−5", &mut out).unwrap();
+    converter.write_to("−2", &mut out).unwrap();
+    assert_eq!(out, ">−●○●−●○");
+
+    let mut buf = [0u8; 12];
+    let len = converter.convert_to_slice("−5", &mut buf).unwrap();
+    assert_eq!(std::str::from_utf8(&buf[..len]).unwrap(), "−●○●");
+    assert_eq!(
+        converter.convert_to_slice("−5", &mut buf[..11]),
+        Err(Error::BufferTooSmall {
+            needed: 12,
+            available: 11
+        })
+    );
+    assert_eq!(
+        converter.convert_to_slice("−x", &mut buf),
+        Err(Error::InvalidCharacter {
+            ch: 'x',
+            byte_offset: 3,
+            char_index: 1
+        })
+    );
+}
+
+#[test]
+fn test_unsigned_tables_reject_negative_values() {
+    // Without signs, '-' is just an invalid character
+    assert_eq!(
+        convert_base("-1", base::DEC, base::HEX),
+        Err(Error::InvalidCharacter {
+            ch: '-',
+            byte_offset: 0,
+            char_index: 0
+        })
+    );
+
+    let src = Alphabet::new(base::DEC)
+        .unwrap()
+        .with_signs('-', None)
+        .unwrap();
+    let converter = Converter::from_alphabets(src, Alphabet::new(base::HEX).unwrap());
+    assert_eq!(converter.convert("-1"), Err(Error::NegativeValue));
+    assert_eq!(converter.convert("-0").unwrap(), "0");
+    // Also past the native fast path
+    assert_eq!(
+        converter.convert(&format!("-{}", "9".repeat(50))),
+        Err(Error::NegativeValue)
+    );
+    assert_eq!(converter.encode(-1i32), Err(Error::NegativeValue));
+    assert_eq!(converter.format(&BigInt::from(-1i32)), Err(Error::NegativeValue));
+    assert_eq!(converter.decode::<i32>("-12").unwrap(), -12);
+}
+
+#[test]
+fn test_sign_configuration() {
+    let hex = Alphabet::new(base::HEX).unwrap();
+    assert_eq!(hex.negative_sign(), None);
+    assert_eq!(hex.positive_sign(), None);
+    assert_eq!(
+        hex.clone().with_signs('a', None),
+        Err(Error::SignConflict {
+            table: Table::Alphabet,
+            ch: 'a'
+        })
+    );
+    assert_eq!(
+        hex.clone().with_signs('-', Some('f')),
+        Err(Error::SignConflict {
+            table: Table::Alphabet,
+            ch: 'f'
+        })
+    );
+    assert_eq!(
+        hex.clone().with_signs('~', Some('~')),
+        Err(Error::SignConflict {
+            table: Table::Alphabet,
+            ch: '~'
+        })
+    );
+    assert_eq!(
+        Converter::new("-0123456789", base::HEX).with_signs('-', None),
+        Err(Error::SignConflict {
+            table: Table::Source,
+            ch: '-'
+        })
+    );
+
+    // Non-ASCII signs with an ASCII table
+    let signed = hex.clone().with_signs('−', None).unwrap();
+    assert_eq!(signed.negative_sign(), Some('−'));
+    assert_ne!(signed, hex);
+    assert_eq!(
+        format!("{:?}", hex),
+        "Alphabet { table: \"0123456789abcdef\", negative_sign: None, positive_sign: None, \
+         radix_point: None, repetend_marks: None, exponent_marker: None }"
+    );
+    assert!(format!("{:?}", signed).contains("negative_sign: Some('−')"));
+    assert_eq!(
+        signed.parse("−x"),
+        Err(Error::InvalidCharacter {
+            ch: 'x',
+            byte_offset: 3,
+            char_index: 1
+        })
+    );
+}
+
+#[test]
+fn test_native_signed_round_trip() {
+    let alphabet = Alphabet::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz")
+        .unwrap()
+        .with_signs('-', Some('+'))
+        .unwrap();
+    for v in [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX] {
+        let encoded = alphabet.encode(v).unwrap();
+        assert_eq!(encoded, alphabet.format(&BigInt::from(v)).unwrap());
+        assert_eq!(alphabet.decode::<i128>(&encoded).unwrap(), v);
+    }
+    assert_eq!(alphabet.decode::<i8>("-24").unwrap(), -128);
+    assert_eq!(
+        alphabet.decode::<i8>("-25"),
+        Err(Error::Overflow { ty: "i8" })
+    );
+    assert_eq!(
+        alphabet.decode::<u8>("-1"),
+        Err(Error::Overflow { ty: "u8" })
+    );
+    assert_eq!(alphabet.decode::<u8>("-0").unwrap(), 0);
+    assert_eq!(alphabet.decode::<u8>("+41").unwrap(), 249);
+}
+
+#[test]
+fn test_twos_complement() {
+    let hex = Alphabet::new(base::HEX).unwrap();
+    for v in -128i32..128 {
+        let encoded = hex.encode_twos_complement(&BigInt::from(v), 2).unwrap();
+        assert_eq!(encoded, format!("{:02x}", v as u8));
+        assert_eq!(
+            hex.decode_twos_complement(&encoded).unwrap(),
+            BigInt::from(v)
+        );
+    }
+    for v in [-129, 128] {
+        assert_eq!(
+            hex.encode_twos_complement(&BigInt::from(v), 2),
+            Err(Error::WidthExceeded { width: 2 })
+        );
+    }
+    let encoded = hex
+        .encode_twos_complement(&BigInt::from(i128::MIN), 32)
+        .unwrap();
+    assert_eq!(encoded, format!("{:032x}", i128::MIN));
+    assert_eq!(
+        hex.decode_twos_complement(&encoded).unwrap(),
+        BigInt::from(i128::MIN)
+    );
+    assert_eq!(hex.encode_twos_complement(&BigInt::zero(), 0).unwrap(), "");
+    assert_eq!(
+        hex.encode_twos_complement(&BigInt::from(1u8), 0),
+        Err(Error::WidthExceeded { width: 0 })
+    );
+    // Rejected before computing base^width
+    assert_eq!(
+        hex.encode_twos_complement(&BigInt::zero(), 4_000_000_000),
+        Err(Error::WidthExceeded {
+            width: 4_000_000_000
+        })
+    );
+    assert_eq!(
+        hex.encode_twos_complement(&BigInt::zero(), MAX_FRACTION_DIGITS)
+            .unwrap()
+            .len(),
+        MAX_FRACTION_DIGITS
+    );
+    let long = "f".repeat(MAX_FRACTION_DIGITS + 1);
+    assert_eq!(
+        hex.decode_twos_complement(&long),
+        Err(Error::WidthExceeded {
+            width: MAX_FRACTION_DIGITS + 1
+        })
+    );
+
+    // Ten's complement; an odd base splits its range evenly around zero
+    let dec = Alphabet::new(base::DEC).unwrap();
+    assert_eq!(
+        dec.encode_twos_complement(&BigInt::from(-1i32), 3).unwrap(),
+        "999"
+    );
+    assert_eq!(
+        dec.decode_twos_complement("500").unwrap(),
+        BigInt::from(-500i32)
+    );
+    let ternary = Alphabet::new("012").unwrap();
+    assert_eq!(
+        ternary
+            .encode_twos_complement(&BigInt::from(4i32), 2)
+            .unwrap(),
+        "11"
+    );
+    assert_eq!(
+        ternary
+            .encode_twos_complement(&BigInt::from(-4i32), 2)
+            .unwrap(),
+        "12"
+    );
+    assert!(
+        ternary
+            .encode_twos_complement(&BigInt::from(5i32), 2)
+            .is_err()
+    );
+    assert!(
+        ternary
+            .encode_twos_complement(&BigInt::from(-5i32), 2)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_zigzag() {
+    let hex = Alphabet::new(base::HEX)
+        .unwrap()
+        .with_signs('-', Some('+'))
+        .unwrap();
+    for v in -1000i64..1000 {
+        let value = BigInt::from(v);
+        let encoded = hex.encode_zigzag(&value);
+        assert_eq!(encoded, format!("{:x}", (v << 1) ^ (v >> 63)));
+        assert_eq!(hex.decode_zigzag(&encoded).unwrap(), value);
+    }
+    assert_eq!(
+        hex.decode_zigzag("-1"),
+        Err(Error::InvalidCharacter {
+            ch: '-',
+            byte_offset: 0,
+            char_index: 0
+        })
+    );
+}